@@ -1,7 +1,3 @@
-use validrs::rules::contains::ValidateContains;
-use validrs::rules::length::ValidateLength;
-use validrs::rules::range::ValidateRange;
-use validrs::rules::required::ValidateRequired;
 use validrs::validate::Validate as _;
 use validrs_derive::Valid;
 
@@ -40,6 +36,7 @@ struct User {
     email: String,
 
     #[valid(contains(["http"], msg = "The provided url is not correct"))]
+    #[allow(clippy::box_collection)]
     url: Box<String>,
 
     #[valid(required)]
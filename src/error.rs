@@ -1,15 +1,24 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::path::{Path, PathSegment};
+use crate::rules::bound::BoundKind;
+use crate::rules::color::ColorFormat;
+use crate::rules::ip::IpVersions;
+
 pub type Result<T> = core::result::Result<T, Error>;
 
-#[derive(Debug, thiserror::Error)]
+/// The failure of a single rule. Every variant has a stable [`code`](Error::code) and
+/// [`params`](Error::params), a custom `msg` keeps both and only replaces the text.
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum Error {
-    #[error("Invalid length: min = {min:?}, max = {max:?}")]
-    InvalidLength {
-        min: Option<usize>,
-        max: Option<usize>,
-    },
+    #[error("Invalid length: must be {kind} {limit}")]
+    InvalidLength { kind: BoundKind, limit: usize },
+
+    #[error("Value is out of range: must be {kind} {limit}")]
+    OutOfRange { kind: BoundKind, limit: String },
 
     #[error("Do not contains")]
-    DoNotContains, /*  { values: Vec<String> } */
+    DoNotContains { values: Vec<String> },
 
     #[error("Field must be required")]
     Required,
@@ -24,7 +33,22 @@ pub enum Error {
     Email,
 
     #[error("Ip is invalid")]
-    Ip,
+    Ip { version: Option<IpVersions> },
+
+    #[error("Invalid phone number")]
+    Phone,
+
+    #[error("Invalid color format")]
+    Color { format: Option<ColorFormat> },
+
+    #[error("Color values out of range")]
+    ColorOutOfRange,
+
+    #[error("String doesn't match the pattern")]
+    NoMatch { pattern: String },
+
+    #[error("Invalid regex pattern: {error}")]
+    InvalidPattern { error: String },
 
     #[error("String is not alphanumeric")]
     Alphanumeric,
@@ -38,27 +62,360 @@ pub enum Error {
     #[error("String is not lowercase")]
     Lowercase,
 
-    #[error(transparent)]
-    Color(#[from] crate::rules::color::ColorError),
+    #[error("String is not uppercase")]
+    Uppercase,
+
+    #[error("Must match `{other}`")]
+    MustMatch { other: String },
 
-    #[error(transparent)]
-    Phone(#[from] crate::rules::phone::PhoneError),
+    #[error("Must be greater than `{other}`")]
+    NotGreater { other: String },
 
+    #[error("Must be less than `{other}`")]
+    NotLess { other: String },
+
+    #[error("At least one of {} is required", fields.join(", "))]
+    OneOfRequired { fields: Vec<String> },
+
+    #[error("Only one of {} may be set", fields.join(", "))]
+    MutuallyExclusive { fields: Vec<String> },
+
+    #[error("Exactly one of {} is required", fields.join(", "))]
+    ExactlyOne { fields: Vec<String> },
+
+    #[error("Variant `{variant}` is not allowed")]
+    RejectedVariant { variant: String },
+
+    /// A rule failure reported with the `msg` of its attribute.
+    #[error("{message}")]
+    WithMessage { error: Box<Error>, message: String },
+
+    /// Failure of a `custom` or `schema` function.
     #[error("{0}")]
     Custom(String),
 }
 
+impl Error {
+    /// Replaces the text of the error with `msg`, when one is given.
+    pub fn with_message(self, msg: Option<String>) -> Self {
+        match (msg, self) {
+            (None, error) => error,
+            (Some(message), Error::WithMessage { error, .. }) => {
+                Error::WithMessage { error, message }
+            }
+            (Some(message), error) => Error::WithMessage {
+                error: Box::new(error),
+                message,
+            },
+        }
+    }
+
+    /// The failed rule without its custom message.
+    pub fn kind(&self) -> &Error {
+        match self {
+            Error::WithMessage { error, .. } => error.kind(),
+            error => error,
+        }
+    }
+
+    /// Custom text of the error, the `msg` of its attribute or the text of a
+    /// `custom` function.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Error::WithMessage { message, .. } | Error::Custom(message) => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Machine-readable name of the failed rule, stable across releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::InvalidLength { .. } => "length",
+            Error::OutOfRange { .. } => "range",
+            Error::DoNotContains { .. } => "contains",
+            Error::Required => "required",
+            Error::Negative => "negative",
+            Error::Positive => "positive",
+            Error::Email => "email",
+            Error::Ip { .. } => "ip",
+            Error::Phone => "phone",
+            Error::Color { .. } => "color",
+            Error::ColorOutOfRange => "color_out_of_range",
+            Error::NoMatch { .. } => "regex",
+            Error::InvalidPattern { .. } => "regex_pattern",
+            Error::Alphanumeric => "alphanumeric",
+            Error::Alphabetic => "alphabetic",
+            Error::Ascii => "ascii",
+            Error::Lowercase => "lowercase",
+            Error::Uppercase => "uppercase",
+            Error::MustMatch { .. } => "must_match",
+            Error::NotGreater { .. } => "gt_field",
+            Error::NotLess { .. } => "lt_field",
+            Error::OneOfRequired { .. } => "one_of_required",
+            Error::MutuallyExclusive { .. } => "mutually_exclusive",
+            Error::ExactlyOne { .. } => "exactly_one",
+            Error::RejectedVariant { .. } => "rejected_variant",
+            Error::WithMessage { error, .. } => error.code(),
+            Error::Custom(_) => "custom",
+        }
+    }
+
+    /// Values the rule was checked against, e.g. `kind` and `limit` of a length.
+    pub fn params(&self) -> BTreeMap<&'static str, String> {
+        match self {
+            Error::InvalidLength { kind, limit } => BTreeMap::from([
+                ("kind", kind.code().to_string()),
+                ("limit", limit.to_string()),
+            ]),
+            Error::OutOfRange { kind, limit } => {
+                BTreeMap::from([("kind", kind.code().to_string()), ("limit", limit.clone())])
+            }
+            Error::DoNotContains { values } => BTreeMap::from([("values", values.join(","))]),
+            Error::Ip {
+                version: Some(version),
+            } => BTreeMap::from([("version", version.code().to_string())]),
+            Error::Color {
+                format: Some(format),
+            } => BTreeMap::from([("format", format.code().to_string())]),
+            Error::NoMatch { pattern } => BTreeMap::from([("pattern", pattern.clone())]),
+            Error::InvalidPattern { error } => BTreeMap::from([("error", error.clone())]),
+            Error::MustMatch { other } | Error::NotGreater { other } | Error::NotLess { other } => {
+                BTreeMap::from([("other", other.clone())])
+            }
+            Error::OneOfRequired { fields }
+            | Error::MutuallyExclusive { fields }
+            | Error::ExactlyOne { fields } => BTreeMap::from([("fields", fields.join(","))]),
+            Error::RejectedVariant { variant } => BTreeMap::from([("variant", variant.clone())]),
+            Error::WithMessage { error, .. } => error.params(),
+            _ => BTreeMap::new(),
+        }
+    }
+}
+
+/// The generic form of an [`Error`], for reporting it outside of Rust, e.g. in an API
+/// response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub code: &'static str,
+    pub message: Option<String>,
+    pub params: BTreeMap<&'static str, String>,
+}
+
+impl From<&Error> for ValidationError {
+    fn from(error: &Error) -> Self {
+        Self {
+            code: error.code(),
+            message: error.message().map(str::to_string),
+            params: error.params(),
+        }
+    }
+}
+
+impl From<Error> for ValidationError {
+    fn from(error: Error) -> Self {
+        Self::from(&error)
+    }
+}
+
+/// Every failure of a single `Validate::validate` run, grouped by the path of the
+/// value that failed.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationErrors {
+    fields: BTreeMap<Path, Vec<Error>>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, path: impl Into<Path>, error: Error) {
+        self.fields.entry(path.into()).or_default().push(error);
+    }
+
+    /// Moves every error of `other` under `prefix`, e.g. the errors of a nested struct
+    /// under the name of the field holding it.
+    pub fn merge(&mut self, prefix: impl Into<Path>, other: ValidationErrors) {
+        let prefix = prefix.into();
+        for (path, errors) in other.fields {
+            self.fields
+                .entry(path.prefixed(&prefix))
+                .or_default()
+                .extend(errors);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Total number of errors across all paths.
+    pub fn len(&self) -> usize {
+        self.fields.values().map(Vec::len).sum()
+    }
+
+    /// Errors reported for the top-level field `field`.
+    pub fn field(&self, field: &str) -> Option<&[Error]> {
+        self.get(&Path::field(field))
+    }
+
+    pub fn get(&self, path: &Path) -> Option<&[Error]> {
+        self.fields.get(path).map(Vec::as_slice)
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = (&Path, &[Error])> {
+        self.fields
+            .iter()
+            .map(|(path, errors)| (path, errors.as_slice()))
+    }
+
+    /// Every error together with its path, one entry per error.
+    pub fn errors(&self) -> impl Iterator<Item = (&Path, &Error)> {
+        self.fields
+            .iter()
+            .flat_map(|(path, errors)| errors.iter().map(move |error| (path, error)))
+    }
+
+    /// Every error in its generic form together with its path.
+    pub fn validation_errors(&self) -> impl Iterator<Item = (&Path, ValidationError)> {
+        self.errors()
+            .map(|(path, error)| (path, ValidationError::from(error)))
+    }
+
+    /// Keeps only the errors whose path starts with one of the top-level `fields`,
+    /// given by position, e.g. `"0"`, for tuple structs.
+    pub fn retain_fields(&mut self, fields: &[&str]) {
+        self.fields.retain(|path, _| match path.segments().first() {
+            Some(PathSegment::Field(name)) => fields.contains(&name.as_str()),
+            Some(PathSegment::Index(index)) => fields.contains(&index.to_string().as_str()),
+            _ => false,
+        });
+    }
+
+    pub fn into_result(self) -> core::result::Result<(), Self> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self),
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (path, error)) in self.errors().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            match path.is_root() {
+                true => write!(f, "{error}")?,
+                false => write!(f, "{path}: {error}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
 #[macro_export]
 macro_rules! validate_error {
     ($result:expr, $msg:expr, $default:expr) => {
         match $result {
             Ok(_) => (),
-            Err(_) => return Err($msg.map(Error::Custom).unwrap_or($default)),
+            Err(_) => return Err($default.with_message($msg)),
         }
     };
     ($condition:expr, $msg:expr, $default:expr) => {
         if !$condition {
-            return Err($msg.map(Error::Custom).unwrap_or($default));
+            return Err($default.with_message($msg));
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ValidationError, ValidationErrors};
+    use crate::path::{Path, PathSegment};
+    use crate::rules::bound::BoundKind;
+    use crate::rules::color::ColorFormat;
+    use crate::rules::ip::IpVersions;
+
+    #[test]
+    fn test_validation_errors_group_by_field() {
+        let mut errors = ValidationErrors::new();
+        errors.add("name", Error::Required);
+        errors.add("name", Error::Alphabetic);
+        errors.add("age", Error::Positive);
+
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors.field("name").unwrap().len(), 2);
+        assert_eq!(errors.field("age").unwrap().len(), 1);
+        assert!(errors.field("email").is_none());
+        assert!(errors.into_result().is_err());
+    }
+
+    #[test]
+    fn test_display_with_paths() {
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            Path::field("addresses")
+                .join(PathSegment::Index(2))
+                .join(PathSegment::Field("zip".into())),
+            Error::Required,
+        );
+        errors.add(Path::root(), Error::Custom("Dates are out of order".into()));
+
+        assert_eq!(
+            errors.to_string(),
+            "Dates are out of order\naddresses[2].zip: Field must be required"
+        );
+    }
+
+    #[test]
+    fn test_codes_and_params() {
+        let error = Error::InvalidLength {
+            kind: BoundKind::Max,
+            limit: 16,
+        }
+        .with_message(Some("Too long".into()));
+
+        assert_eq!(error.to_string(), "Too long");
+        assert_eq!(
+            ValidationError::from(&error),
+            ValidationError {
+                code: "length",
+                message: Some("Too long".into()),
+                params: [("kind", "max".into()), ("limit", "16".into())].into(),
+            }
+        );
+        assert!(matches!(
+            error.kind(),
+            Error::InvalidLength { limit: 16, .. }
+        ));
+        assert_eq!(Error::Required.code(), "required");
+        assert_eq!(Error::Required.message(), None);
+
+        let error = Error::NoMatch {
+            pattern: "^[a-z]+$".into(),
+        };
+        assert_eq!(error.params(), [("pattern", "^[a-z]+$".into())].into());
+        let error = Error::DoNotContains {
+            values: vec!["@".into(), ".".into()],
+        };
+        assert_eq!(error.params(), [("values", "@,.".into())].into());
+        let error = Error::Ip {
+            version: Some(IpVersions::V6),
+        };
+        assert_eq!(error.params(), [("version", "v6".into())].into());
+        let error = Error::Color {
+            format: Some(ColorFormat::Rgba),
+        };
+        assert_eq!(error.params(), [("format", "rgba".into())].into());
+        assert!(Error::Color { format: None }.params().is_empty());
+    }
+
+    #[test]
+    fn test_empty_validation_errors() {
+        assert!(ValidationErrors::new().into_result().is_ok());
+    }
+}
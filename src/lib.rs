@@ -1,3 +1,5 @@
 pub mod error;
+pub mod patch;
+pub mod path;
 pub mod rules;
 pub mod validate;
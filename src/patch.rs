@@ -0,0 +1,90 @@
+/// Field of a PATCH payload that tells an absent value apart from an explicit `null`.
+///
+/// Rules skip [`Patch::Absent`] values, treat [`Patch::Null`] like `None` and validate
+/// [`Patch::Value`] like the wrapped value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Patch<T> {
+    #[default]
+    Absent,
+    Null,
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Patch::Absent)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Patch::Null)
+    }
+
+    pub fn is_value(&self) -> bool {
+        matches!(self, Patch::Value(_))
+    }
+
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(value) => Some(value),
+            Patch::Absent | Patch::Null => None,
+        }
+    }
+
+    pub fn as_ref(&self) -> Patch<&T> {
+        match self {
+            Patch::Absent => Patch::Absent,
+            Patch::Null => Patch::Null,
+            Patch::Value(value) => Patch::Value(value),
+        }
+    }
+
+    /// `None` for absent fields, `Some(None)` for an explicit `null`.
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Patch::Absent => None,
+            Patch::Null => Some(None),
+            Patch::Value(value) => Some(Some(value)),
+        }
+    }
+}
+
+/// Lets generated code skip absent fields of any type: `Patch` answers through
+/// [`Patch::is_absent`], every other type is never absent. Written as
+/// `(&MaybePatch(&value)).is_absent()` with both traits in scope.
+#[doc(hidden)]
+pub struct MaybePatch<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait IsPatch {
+    fn is_absent(&self) -> bool;
+}
+
+impl<T> IsPatch for MaybePatch<'_, Patch<T>> {
+    fn is_absent(&self) -> bool {
+        self.0.is_absent()
+    }
+}
+
+#[doc(hidden)]
+pub trait IsNotPatch {
+    fn is_absent(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> IsNotPatch for &MaybePatch<'_, T> {}
+
+impl<T> From<Option<T>> for Patch<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Patch::Value(value),
+            None => Patch::Null,
+        }
+    }
+}
+
+impl<T> From<T> for Patch<T> {
+    fn from(value: T) -> Self {
+        Patch::Value(value)
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+
+/// Location of a value inside the validated struct, e.g. `addresses[2].zip`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Path {
+    segments: Vec<PathSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+    Key(String),
+}
+
+impl Path {
+    /// Empty path, pointing at the validated value itself.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn field(name: impl Into<String>) -> Self {
+        Self::root().join(PathSegment::Field(name.into()))
+    }
+
+    pub fn index(index: usize) -> Self {
+        Self::root().join(PathSegment::Index(index))
+    }
+
+    pub fn key(key: impl ToString) -> Self {
+        Self::root().join(PathSegment::Key(key.to_string()))
+    }
+
+    pub fn join(mut self, segment: PathSegment) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    /// Puts `prefix` in front of this path.
+    pub fn prefixed(self, prefix: &Path) -> Self {
+        let mut segments = prefix.segments.clone();
+        segments.extend(self.segments);
+        Self { segments }
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// Renders the path as a JSON Pointer (RFC 6901), e.g. `/addresses/2/zip`.
+    pub fn to_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for segment in &self.segments {
+            pointer.push('/');
+            match segment {
+                PathSegment::Field(name) | PathSegment::Key(name) => {
+                    pointer.push_str(&name.replace('~', "~0").replace('/', "~1"))
+                }
+                PathSegment::Index(index) => pointer.push_str(&index.to_string()),
+            }
+        }
+        pointer
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+                PathSegment::Key(key) => write!(f, "[{key:?}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for Path {
+    fn from(name: &str) -> Self {
+        Self::field(name)
+    }
+}
+
+impl From<String> for Path {
+    fn from(name: String) -> Self {
+        Self::field(name)
+    }
+}
+
+impl From<usize> for Path {
+    fn from(index: usize) -> Self {
+        Self::index(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Path, PathSegment};
+
+    #[test]
+    fn test_display_path() {
+        let path = Path::field("addresses")
+            .join(PathSegment::Index(2))
+            .join(PathSegment::Field("zip".into()));
+        assert_eq!(path.to_string(), "addresses[2].zip");
+
+        let path = Path::field("labels").join(PathSegment::Key("en".into()));
+        assert_eq!(path.to_string(), r#"labels["en"]"#);
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        let path = Path::field("addresses")
+            .join(PathSegment::Index(2))
+            .join(PathSegment::Field("zip".into()));
+        assert_eq!(path.to_json_pointer(), "/addresses/2/zip");
+
+        let path = Path::field("a/b").join(PathSegment::Key("m~n".into()));
+        assert_eq!(path.to_json_pointer(), "/a~1b/m~0n");
+
+        assert_eq!(Path::root().to_json_pointer(), "");
+    }
+
+    #[test]
+    fn test_prefixed() {
+        let path =
+            Path::field("zip").prefixed(&Path::field("addresses").join(PathSegment::Index(0)));
+        assert_eq!(path.to_string(), "addresses[0].zip");
+    }
+}
@@ -1,18 +1,19 @@
 use std::borrow::Cow;
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateAlphanumeric {
     fn validate_alphanumeric(&self, msg: Option<String>) -> Result<()> {
-        let err = msg.map(Error::Custom).unwrap_or(Error::Alphanumeric);
+        let err = Error::Alphanumeric.with_message(msg);
 
         let Some(str) = self.str() else {
-            return Err(err);
+            return Ok(());
         };
 
         match str.chars().all(|c| c.is_alphanumeric()) {
             true => Ok(()),
-            false => return Err(err),
+            false => Err(err),
         }
     }
 
@@ -55,6 +56,20 @@ impl<T: ValidateAlphanumeric> ValidateAlphanumeric for &T {
     }
 }
 
+impl<T: ValidateAlphanumeric> ValidateAlphanumeric for Patch<T> {
+    fn validate_alphanumeric(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_alphanumeric(msg),
+            Patch::Value(value) => value.validate_alphanumeric(msg),
+        }
+    }
+
+    fn str(&self) -> Option<&str> {
+        self.value().and_then(|s| s.str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
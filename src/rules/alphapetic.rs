@@ -1,18 +1,19 @@
 use std::borrow::Cow;
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateAlphabetic {
     fn validate_alphabetic(&self, msg: Option<String>) -> Result<()> {
-        let err = msg.map(Error::Custom).unwrap_or(Error::Alphabetic);
+        let err = Error::Alphabetic.with_message(msg);
 
         let Some(str) = self.str() else {
-            return Err(err);
+            return Ok(());
         };
 
         match str.chars().all(|c| c.is_alphabetic()) {
             true => Ok(()),
-            false => return Err(err),
+            false => Err(err),
         }
     }
 
@@ -55,6 +56,20 @@ impl<T: ValidateAlphabetic> ValidateAlphabetic for &T {
     }
 }
 
+impl<T: ValidateAlphabetic> ValidateAlphabetic for Patch<T> {
+    fn validate_alphabetic(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_alphabetic(msg),
+            Patch::Value(value) => value.validate_alphabetic(msg),
+        }
+    }
+
+    fn str(&self) -> Option<&str> {
+        self.value().and_then(|s| s.str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
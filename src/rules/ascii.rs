@@ -1,18 +1,19 @@
 use std::borrow::Cow;
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateAscii {
     fn validate_ascii(&self, msg: Option<String>) -> Result<()> {
-        let err = msg.map(Error::Custom).unwrap_or(Error::Ascii);
+        let err = Error::Ascii.with_message(msg);
 
         let Some(str) = self.str() else {
-            return Err(err);
+            return Ok(());
         };
 
         match str.is_ascii() {
             true => Ok(()),
-            false => return Err(err),
+            false => Err(err),
         }
     }
 
@@ -55,6 +56,20 @@ impl<T: ValidateAscii> ValidateAscii for &T {
     }
 }
 
+impl<T: ValidateAscii> ValidateAscii for Patch<T> {
+    fn validate_ascii(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_ascii(msg),
+            Patch::Value(value) => value.validate_ascii(msg),
+        }
+    }
+
+    fn str(&self) -> Option<&str> {
+        self.value().and_then(|s| s.str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,120 @@
+use std::fmt;
+
+/// Lower or upper limit of `validate_length` and `validate_range`.
+///
+/// `len(1..=16)` gives an inclusive min of 1 and an inclusive max of 16, `rng(..0.5)`
+/// an exclusive max of 0.5. `Equal` fixes the value whichever side it is passed as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bound<T> {
+    Inclusive(T),
+    Exclusive(T),
+    Equal(T),
+}
+
+/// Which bound a value failed, recorded in length and range errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundKind {
+    Min,
+    ExclusiveMin,
+    Max,
+    ExclusiveMax,
+    Equal,
+}
+
+impl<T> Bound<T> {
+    pub fn value(&self) -> &T {
+        match self {
+            Bound::Inclusive(value) | Bound::Exclusive(value) | Bound::Equal(value) => value,
+        }
+    }
+
+    pub fn into_value(self) -> T {
+        match self {
+            Bound::Inclusive(value) | Bound::Exclusive(value) | Bound::Equal(value) => value,
+        }
+    }
+
+    pub fn min_kind(&self) -> BoundKind {
+        match self {
+            Bound::Inclusive(_) => BoundKind::Min,
+            Bound::Exclusive(_) => BoundKind::ExclusiveMin,
+            Bound::Equal(_) => BoundKind::Equal,
+        }
+    }
+
+    pub fn max_kind(&self) -> BoundKind {
+        match self {
+            Bound::Inclusive(_) => BoundKind::Max,
+            Bound::Exclusive(_) => BoundKind::ExclusiveMax,
+            Bound::Equal(_) => BoundKind::Equal,
+        }
+    }
+}
+
+impl<T: PartialOrd> Bound<T> {
+    /// Whether `value` satisfies this bound used as a minimum. A value that is not
+    /// comparable, such as NaN, fails every bound.
+    pub fn allows_min(&self, value: &T) -> bool {
+        match self {
+            Bound::Inclusive(min) => value >= min,
+            Bound::Exclusive(min) => value > min,
+            Bound::Equal(equal) => value == equal,
+        }
+    }
+
+    /// Whether `value` satisfies this bound used as a maximum.
+    pub fn allows_max(&self, value: &T) -> bool {
+        match self {
+            Bound::Inclusive(max) => value <= max,
+            Bound::Exclusive(max) => value < max,
+            Bound::Equal(equal) => value == equal,
+        }
+    }
+}
+
+impl BoundKind {
+    /// Machine-readable name, the `kind` param of length and range errors.
+    pub fn code(&self) -> &'static str {
+        match self {
+            BoundKind::Min => "min",
+            BoundKind::ExclusiveMin => "exclusive_min",
+            BoundKind::Max => "max",
+            BoundKind::ExclusiveMax => "exclusive_max",
+            BoundKind::Equal => "equal",
+        }
+    }
+}
+
+impl fmt::Display for BoundKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BoundKind::Min => "at least",
+            BoundKind::ExclusiveMin => "greater than",
+            BoundKind::Max => "at most",
+            BoundKind::ExclusiveMax => "less than",
+            BoundKind::Equal => "exactly",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bound, BoundKind};
+
+    #[test]
+    fn test_inclusive_and_exclusive_bounds() {
+        assert!(Bound::Inclusive(1).allows_min(&1));
+        assert!(!Bound::Exclusive(1).allows_min(&1));
+        assert!(Bound::Inclusive(16).allows_max(&16));
+        assert!(!Bound::Exclusive(16).allows_max(&16));
+        assert!(!Bound::Inclusive(0.0).allows_min(&f64::NAN));
+    }
+
+    #[test]
+    fn test_equal_bound() {
+        assert!(Bound::Equal(4).allows_min(&4));
+        assert!(!Bound::Equal(4).allows_max(&5));
+        assert_eq!(Bound::Equal(4).min_kind(), BoundKind::Equal);
+        assert_eq!(Bound::Exclusive(4).max_kind(), BoundKind::ExclusiveMax);
+    }
+}
@@ -1,9 +1,9 @@
 use std::borrow::Cow;
 
-use thiserror::Error;
-
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::patch::Patch;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColorFormat {
     Hex,
     Rgb,
@@ -12,24 +12,28 @@ pub enum ColorFormat {
     Hsla,
 }
 
-#[derive(Debug, Error)]
-pub enum ColorError {
-    #[error("Invalid color format")]
-    InvalidFormat,
-
-    #[error("Color values out of range")]
-    OutOfRange,
-
-    #[error("{0}")]
-    Custom(String),
+impl ColorFormat {
+    /// Machine-readable name, the `format` param of color errors.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ColorFormat::Hex => "hex",
+            ColorFormat::Rgb => "rgb",
+            ColorFormat::Rgba => "rgba",
+            ColorFormat::Hsl => "hsl",
+            ColorFormat::Hsla => "hsla",
+        }
+    }
 }
 
+/// Failure of the format checks, [`ValidateColor::validate_color`] records the
+/// requested format.
+const INVALID: Error = Error::Color { format: None };
+
 pub trait ValidateColor {
     fn validate_color(&self, format: Option<ColorFormat>, msg: Option<String>) -> Result<()> {
-        let color = self.color().ok_or(
-            msg.map(ColorError::Custom)
-                .unwrap_or(ColorError::InvalidFormat),
-        )?;
+        let Some(color) = self.color() else {
+            return Ok(());
+        };
 
         match format {
             Some(format) => match format {
@@ -41,6 +45,13 @@ pub trait ValidateColor {
             },
             None => try_detect_format(color),
         }
+        .map_err(|err| {
+            match err {
+                Error::Color { .. } => Error::Color { format },
+                err => err,
+            }
+            .with_message(msg)
+        })
     }
 
     fn color(&self) -> Option<&str>;
@@ -58,38 +69,38 @@ fn try_detect_format(color: &str) -> Result<()> {
     } else if color.starts_with("hsla(") {
         validate_hsla(color)?;
     } else {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
     Ok(())
 }
 
 fn validate_hex(hex: &str) -> Result<()> {
     if !hex.starts_with('#') {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
     let hex = &hex[1..];
 
     if ![3, 4, 6, 8].contains(&hex.len()) {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
     match hex.chars().all(|c| c.is_ascii_hexdigit()) {
         true => Ok(()),
-        false => return Err(ColorError::InvalidFormat.into()),
+        false => Err(INVALID),
     }
 }
 
 fn validate_rgb(rgb: &str) -> Result<()> {
     if !rgb.starts_with("rgb(") || !rgb.ends_with(')') {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
     let inner = &rgb[4..rgb.len() - 1];
     let parts: Vec<&str> = inner.split(',').map(|p| p.trim()).collect();
 
     if parts.len() != 3 {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
     for part in parts {
@@ -101,14 +112,14 @@ fn validate_rgb(rgb: &str) -> Result<()> {
 
 fn validate_rgba(rgba: &str) -> Result<()> {
     if !rgba.starts_with("rgba(") || !rgba.ends_with(')') {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
     let inner = &rgba[5..rgba.len() - 1];
     let parts: Vec<&str> = inner.split(',').map(|p| p.trim()).collect();
 
     if parts.len() != 4 {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
     for (i, part) in parts.iter().enumerate() {
@@ -120,17 +131,15 @@ fn validate_rgba(rgba: &str) -> Result<()> {
 }
 
 fn validate_color_component(s: &str, max: f64) -> Result<()> {
-    if s.ends_with('%') {
-        let percent = s[..s.len() - 1]
-            .parse::<f64>()
-            .map_err(|_| ColorError::InvalidFormat)?;
-        if percent < 0.0 || percent > 100.0 {
-            return Err(ColorError::OutOfRange.into());
+    if let Some(percent) = s.strip_suffix('%') {
+        let percent = percent.parse::<f64>().map_err(|_| INVALID)?;
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(Error::ColorOutOfRange);
         }
     } else {
-        let val = s.parse::<f64>().map_err(|_| ColorError::InvalidFormat)?;
+        let val = s.parse::<f64>().map_err(|_| INVALID)?;
         if val < 0.0 || val > max {
-            return Err(ColorError::OutOfRange.into());
+            return Err(Error::ColorOutOfRange);
         }
     }
     Ok(())
@@ -138,68 +147,66 @@ fn validate_color_component(s: &str, max: f64) -> Result<()> {
 
 fn validate_hsl(hsl: &str) -> Result<()> {
     if !hsl.starts_with("hsl(") || !hsl.ends_with(')') {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
     let inner = &hsl[4..hsl.len() - 1];
     let parts: Vec<&str> = inner.split(',').map(|p| p.trim()).collect();
 
     if parts.len() != 3 {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
-    validate_hue(&parts[0])?;
-    validate_percentage(&parts[1])?;
-    validate_percentage(&parts[2])?;
+    validate_hue(parts[0])?;
+    validate_percentage(parts[1])?;
+    validate_percentage(parts[2])?;
 
     Ok(())
 }
 
 fn validate_hsla(s: &str) -> Result<()> {
     if !s.starts_with("hsla(") || !s.ends_with(')') {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
     let inner = &s[5..s.len() - 1];
     let parts: Vec<&str> = inner.split(',').map(|p| p.trim()).collect();
 
     if parts.len() != 4 {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
 
-    validate_hue(&parts[0])?;
-    validate_percentage(&parts[1])?;
-    validate_percentage(&parts[2])?;
-    validate_alpha(&parts[3])?;
+    validate_hue(parts[0])?;
+    validate_percentage(parts[1])?;
+    validate_percentage(parts[2])?;
+    validate_alpha(parts[3])?;
 
     Ok(())
 }
 
 fn validate_hue(s: &str) -> Result<()> {
-    let val = s.parse::<f64>().map_err(|_| ColorError::InvalidFormat)?;
-    if val < 0.0 || val >= 360.0 {
-        return Err(ColorError::OutOfRange.into());
+    let val = s.parse::<f64>().map_err(|_| INVALID)?;
+    if !(0.0..360.0).contains(&val) {
+        return Err(Error::ColorOutOfRange);
     }
     Ok(())
 }
 
 fn validate_percentage(s: &str) -> Result<()> {
     if !s.ends_with('%') {
-        return Err(ColorError::InvalidFormat.into());
+        return Err(INVALID);
     }
-    let percent = s[..s.len() - 1]
-        .parse::<f64>()
-        .map_err(|_| ColorError::InvalidFormat)?;
-    if percent < 0.0 || percent > 100.0 {
-        return Err(ColorError::OutOfRange.into());
+    let percent = s[..s.len() - 1].parse::<f64>().map_err(|_| INVALID)?;
+    if !(0.0..=100.0).contains(&percent) {
+        return Err(Error::ColorOutOfRange);
     }
     Ok(())
 }
 
 fn validate_alpha(s: &str) -> Result<()> {
-    let val = s.parse::<f64>().map_err(|_| ColorError::InvalidFormat)?;
-    if val < 0.0 || val > 1.0 {
-        return Err(ColorError::OutOfRange.into());
+    let val = s.parse::<f64>().map_err(|_| INVALID)?;
+    if !(0.0..=1.0).contains(&val) {
+        return Err(Error::ColorOutOfRange);
     }
     Ok(())
 }
@@ -240,6 +247,20 @@ impl<T: ValidateColor> ValidateColor for &T {
     }
 }
 
+impl<T: ValidateColor> ValidateColor for Patch<T> {
+    fn validate_color(&self, format: Option<ColorFormat>, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_color(format, msg),
+            Patch::Value(value) => value.validate_color(format, msg),
+        }
+    }
+
+    fn color(&self) -> Option<&str> {
+        self.value().and_then(|s| s.color())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,82 @@
+use crate::error::{Error, Result};
+
+/// Compares a value with another field of the same struct through `PartialEq`/`PartialOrd`.
+pub trait ValidateCompare {
+    fn validate_must_match<T: ?Sized>(
+        &self,
+        other: &T,
+        other_name: &str,
+        msg: Option<String>,
+    ) -> Result<()>
+    where
+        Self: PartialEq<T>,
+    {
+        if self != other {
+            let other = other_name.to_string();
+            return Err(Error::MustMatch { other }.with_message(msg));
+        }
+        Ok(())
+    }
+
+    fn validate_gt_field<T: ?Sized>(
+        &self,
+        other: &T,
+        other_name: &str,
+        msg: Option<String>,
+    ) -> Result<()>
+    where
+        Self: PartialOrd<T>,
+    {
+        if !(self > other) {
+            let other = other_name.to_string();
+            return Err(Error::NotGreater { other }.with_message(msg));
+        }
+        Ok(())
+    }
+
+    fn validate_lt_field<T: ?Sized>(
+        &self,
+        other: &T,
+        other_name: &str,
+        msg: Option<String>,
+    ) -> Result<()>
+    where
+        Self: PartialOrd<T>,
+    {
+        if !(self < other) {
+            let other = other_name.to_string();
+            return Err(Error::NotLess { other }.with_message(msg));
+        }
+        Ok(())
+    }
+}
+
+impl<T: ?Sized> ValidateCompare for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidateCompare;
+
+    #[test]
+    fn test_must_match() {
+        let password = "secret".to_string();
+        assert!(
+            password
+                .validate_must_match(&password, "password", None)
+                .is_ok()
+        );
+        assert!(
+            "secret"
+                .validate_must_match("Secret", "password", None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_compare_fields() {
+        assert!(10.validate_gt_field(&5, "min_price", None).is_ok());
+        assert!(5.validate_gt_field(&5, "min_price", None).is_err());
+        assert!(1.5.validate_lt_field(&2.0, "max_price", None).is_ok());
+        assert!(f64::NAN.validate_lt_field(&2.0, "max_price", None).is_err());
+    }
+}
@@ -1,14 +1,17 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Display;
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateContains<'a, T: PartialEq> {
-    fn validate_contains(&'a self, values: &'a [T], msg: Option<String>) -> Result<()> {
+    fn validate_contains(&'a self, values: &'a [T], msg: Option<String>) -> Result<()>
+    where
+        T: Display,
+    {
         if !values.iter().all(|v| self.contains_value(v)) {
-            return match msg {
-                Some(msg) => Err(Error::Custom(msg.into())),
-                None => Err(Error::DoNotContains),
-            };
+            let values = values.iter().map(ToString::to_string).collect();
+            return Err(Error::DoNotContains { values }.with_message(msg));
         }
         Ok(())
     }
@@ -73,6 +76,38 @@ macro_rules! validate_type_with_contains_keys {
 validate_type_with_contains_keys!(HashMap<K, V>, K, V);
 validate_type_with_contains_keys!(BTreeMap<K, V>, K, V);
 
+impl<'a, T: PartialEq, C: ValidateContains<'a, T>> ValidateContains<'a, T> for Option<C> {
+    fn validate_contains(&'a self, values: &'a [T], msg: Option<String>) -> Result<()>
+    where
+        T: Display,
+    {
+        match self {
+            Some(value) => value.validate_contains(values, msg),
+            None => Ok(()),
+        }
+    }
+
+    fn contains_value(&self, value: &T) -> bool {
+        self.as_ref().is_some_and(|s| s.contains_value(value))
+    }
+}
+
+impl<'a, T: PartialEq, C: ValidateContains<'a, T>> ValidateContains<'a, T> for Patch<C> {
+    fn validate_contains(&'a self, values: &'a [T], msg: Option<String>) -> Result<()>
+    where
+        T: Display,
+    {
+        match self {
+            Patch::Value(value) => value.validate_contains(values, msg),
+            Patch::Absent | Patch::Null => Ok(()),
+        }
+    }
+
+    fn contains_value(&self, value: &T) -> bool {
+        self.value().is_some_and(|s| s.contains_value(value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -81,25 +116,26 @@ mod tests {
 
     #[test]
     fn test_validate_contains_substring_in_str() {
-        let valid = "test@gmail.com".validate_contains(&["@"], None).unwrap();
-        assert_eq!(valid, ());
+        assert!("test@gmail.com".validate_contains(&["@"], None).is_ok());
     }
 
     #[test]
     fn test_validate_contains_substring_in_string() {
-        let valid = "test@gmail.com"
-            .to_string()
-            .validate_contains(&["@"], None)
-            .unwrap();
-        assert_eq!(valid, ());
+        assert!(
+            "test@gmail.com"
+                .to_string()
+                .validate_contains(&["@"], None)
+                .is_ok()
+        );
     }
 
     #[test]
     fn test_validate_contains_element_in_vec() {
-        let valid = vec!["abc", "def", "ghi"]
-            .validate_contains(&["def"], None)
-            .unwrap();
-        assert_eq!(valid, ());
+        assert!(
+            vec!["abc", "def", "ghi"]
+                .validate_contains(&["def"], None)
+                .is_ok()
+        );
     }
 
     #[test]
@@ -108,7 +144,6 @@ mod tests {
         map.insert(1, "a");
         map.insert(2, "b");
         map.insert(3, "c");
-        let valid = map.validate_contains(&[1], None).unwrap();
-        assert_eq!(valid, ());
+        assert!(map.validate_contains(&[1], None).is_ok());
     }
 }
@@ -3,6 +3,7 @@ use std::{borrow::Cow, sync::LazyLock};
 use regex::Regex;
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
@@ -13,31 +14,31 @@ static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 
 pub trait ValidateEmail {
     fn validate_email(&self, msg: Option<String>) -> Result<()> {
-        if let Some(email) = self.email_string() {
-            if !EMAIL_REGEX.is_match(&email) {
-                return Err(msg.map(Error::Custom).unwrap_or(Error::Email));
-            }
+        if let Some(email) = self.email_string()
+            && !EMAIL_REGEX.is_match(&email)
+        {
+            return Err(Error::Email.with_message(msg));
         }
         Ok(())
     }
 
-    fn email_string(&self) -> Option<Cow<str>>;
+    fn email_string(&self) -> Option<Cow<'_, str>>;
 }
 
 impl ValidateEmail for String {
-    fn email_string(&self) -> Option<Cow<str>> {
+    fn email_string(&self) -> Option<Cow<'_, str>> {
         Some(Cow::from(self))
     }
 }
 
 impl ValidateEmail for &str {
-    fn email_string(&self) -> Option<Cow<str>> {
+    fn email_string(&self) -> Option<Cow<'_, str>> {
         Some(Cow::from(*self))
     }
 }
 
 impl ValidateEmail for Cow<'_, str> {
-    fn email_string(&self) -> Option<Cow<str>> {
+    fn email_string(&self) -> Option<Cow<'_, str>> {
         Some(self.clone())
     }
 }
@@ -46,13 +47,13 @@ impl<T> ValidateEmail for &T
 where
     T: ValidateEmail,
 {
-    fn email_string(&self) -> Option<Cow<str>> {
+    fn email_string(&self) -> Option<Cow<'_, str>> {
         T::email_string(self)
     }
 }
 
 impl<T: ValidateEmail> ValidateEmail for Option<T> {
-    fn email_string(&self) -> Option<Cow<str>> {
+    fn email_string(&self) -> Option<Cow<'_, str>> {
         let Some(s) = self else {
             return None;
         };
@@ -60,6 +61,20 @@ impl<T: ValidateEmail> ValidateEmail for Option<T> {
     }
 }
 
+impl<T: ValidateEmail> ValidateEmail for Patch<T> {
+    fn validate_email(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_email(msg),
+            Patch::Value(value) => value.validate_email(msg),
+        }
+    }
+
+    fn email_string(&self) -> Option<Cow<'_, str>> {
+        self.value().and_then(|s| s.email_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ValidateEmail;
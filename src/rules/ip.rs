@@ -1,19 +1,31 @@
 use std::{borrow::Cow, net::IpAddr, str::FromStr as _};
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IpVersions {
     V4,
     V6,
 }
 
+impl IpVersions {
+    /// Machine-readable name, the `version` param of ip errors.
+    pub fn code(&self) -> &'static str {
+        match self {
+            IpVersions::V4 => "v4",
+            IpVersions::V6 => "v6",
+        }
+    }
+}
+
 // TODO: validate loopback and multicast
 pub trait ValidateIp {
     fn validate_ip(&self, version: Option<IpVersions>, msg: Option<String>) -> Result<()> {
-        let err = msg.map(Error::Custom).unwrap_or(Error::Ip);
         let Some(ip_str) = self.ip_str() else {
-            return Err(err);
+            return Ok(());
         };
+        let err = Error::Ip { version }.with_message(msg);
 
         match IpAddr::from_str(ip_str) {
             Ok(ip) => match version {
@@ -61,6 +73,20 @@ impl<T: ValidateIp> ValidateIp for Option<T> {
     }
 }
 
+impl<T: ValidateIp> ValidateIp for Patch<T> {
+    fn validate_ip(&self, version: Option<IpVersions>, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_ip(version, msg),
+            Patch::Value(value) => value.validate_ip(version, msg),
+        }
+    }
+
+    fn ip_str(&self) -> Option<&str> {
+        self.value().and_then(|s| s.ip_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::ValidateIp;
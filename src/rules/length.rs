@@ -4,61 +4,70 @@ use std::{
     rc::Rc,
     sync::Arc,
 };
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::patch::Patch;
+use crate::rules::bound::Bound;
+
+/// What the length of a string counts. Collections always count their elements.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    /// UTF-8 bytes, as limited by database columns.
+    Bytes,
+    /// Unicode scalar values.
+    #[default]
+    Chars,
+    /// Extended grapheme clusters, the characters a user perceives.
+    Graphemes,
+    /// UTF-16 code units, as counted by `maxlength` in browsers.
+    Utf16,
+}
+
+impl LengthUnit {
+    pub fn count(self, s: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => s.len(),
+            LengthUnit::Chars => s.chars().count(),
+            LengthUnit::Graphemes => s.graphemes(true).count(),
+            LengthUnit::Utf16 => s.encode_utf16().count(),
+        }
+    }
+}
 
 pub trait ValidateLength {
     fn validate_length(
         &self,
-        min: Option<usize>,
-        max: Option<usize>,
+        min: Option<Bound<usize>>,
+        max: Option<Bound<usize>>,
+        unit: LengthUnit,
         msg: Option<String>,
     ) -> Result<()> {
-        match (min, max, msg) {
-            (Some(_), Some(_), Some(msg)) => {
-                if self.length() <= min || self.length() >= max {
-                    return Err(crate::error::Error::Custom(msg));
-                }
-            }
-            (Some(_), Some(_), None) => {
-                if self.length() <= min || self.length() >= max {
-                    return Err(crate::error::Error::InvalidLength { min, max });
-                }
-            }
-            (Some(_), None, Some(msg)) => {
-                if self.length() <= min {
-                    return Err(crate::error::Error::Custom(msg));
-                }
-            }
-            (Some(_), None, None) => {
-                if self.length() <= min {
-                    return Err(crate::error::Error::InvalidLength { min, max });
-                }
-            }
-            (None, Some(_), Some(msg)) => {
-                if self.length() >= max {
-                    return Err(crate::error::Error::Custom(msg));
-                }
-            }
-            (None, Some(_), None) => {
-                if self.length() >= max {
-                    return Err(crate::error::Error::InvalidLength { min, max });
-                }
-            }
-            (None, None, None) | (None, None, Some(_)) => (),
+        let Some(length) = self.length(unit) else {
+            return Ok(());
+        };
+        let failed = match (min, max) {
+            (Some(min), _) if !min.allows_min(&length) => Some((min.min_kind(), min)),
+            (_, Some(max)) if !max.allows_max(&length) => Some((max.max_kind(), max)),
+            _ => None,
+        };
+
+        if let Some((kind, bound)) = failed {
+            let limit = bound.into_value();
+            return Err(Error::InvalidLength { kind, limit }.with_message(msg));
         }
 
         Ok(())
     }
 
-    fn length(&self) -> Option<usize>;
+    fn length(&self, unit: LengthUnit) -> Option<usize>;
 }
 
 macro_rules! validate_type_with_deref {
     ($type:ty) => {
         impl<T: ValidateLength> ValidateLength for $type {
-            fn length(&self) -> Option<usize> {
-                T::length(self)
+            fn length(&self, unit: LengthUnit) -> Option<usize> {
+                T::length(self, unit)
             }
         }
     };
@@ -73,7 +82,7 @@ validate_type_with_deref!(RefMut<'_, T>);
 macro_rules! validate_type_with_len {
     ($type:ty, $($generic:ident),*) => {
         impl<$($generic),*> ValidateLength for $type {
-            fn length(&self) -> Option<usize> {
+            fn length(&self, _unit: LengthUnit) -> Option<usize> {
                 Some(self.len())
             }
         }
@@ -88,45 +97,148 @@ validate_type_with_len!(BTreeSet<T>, T);
 validate_type_with_len!(HashMap<K, V>, K, V);
 validate_type_with_len!(BTreeMap<K, V>, K, V);
 
-macro_rules! validate_type_with_chars {
+macro_rules! validate_type_with_unit {
     ($type:ty) => {
         impl ValidateLength for $type {
-            fn length(&self) -> Option<usize> {
-                Some(self.chars().count())
+            fn length(&self, unit: LengthUnit) -> Option<usize> {
+                Some(unit.count(self))
             }
         }
     };
 }
 
-validate_type_with_chars!(str);
-validate_type_with_chars!(&str);
-validate_type_with_chars!(String);
+validate_type_with_unit!(str);
+validate_type_with_unit!(&str);
+validate_type_with_unit!(String);
 
 impl<T: ValidateLength> ValidateLength for Option<T> {
-    fn length(&self) -> Option<usize> {
+    fn length(&self, unit: LengthUnit) -> Option<usize> {
         let Some(s) = self else {
             return None;
         };
 
-        T::length(s)
+        T::length(s, unit)
+    }
+}
+
+impl<T: ValidateLength> ValidateLength for Patch<T> {
+    fn validate_length(
+        &self,
+        min: Option<Bound<usize>>,
+        max: Option<Bound<usize>>,
+        unit: LengthUnit,
+        msg: Option<String>,
+    ) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_length(min, max, unit, msg),
+            Patch::Value(value) => value.validate_length(min, max, unit, msg),
+        }
+    }
+
+    fn length(&self, unit: LengthUnit) -> Option<usize> {
+        self.value().and_then(|s| s.length(unit))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::LengthUnit::{Bytes, Chars, Graphemes, Utf16};
     use super::ValidateLength;
+    use crate::error::Error;
+    use crate::rules::bound::Bound::{Equal, Exclusive, Inclusive};
+    use crate::rules::bound::BoundKind;
 
     #[test]
     fn test_validate_str_length() {
-        let valid = "hello".validate_length(Some(1), Some(10), None).unwrap();
-        assert_eq!(valid, ());
+        assert!(
+            "hello"
+                .validate_length(Some(Inclusive(1)), Some(Inclusive(10)), Chars, None)
+                .is_ok()
+        );
     }
 
     #[test]
     fn test_validate_vec_length() {
-        let valid = vec![1, 2, 3]
-            .validate_length(Some(1), Some(10), None)
-            .unwrap();
-        assert_eq!(valid, ());
+        assert!(
+            vec![1, 2, 3]
+                .validate_length(Some(Inclusive(1)), Some(Inclusive(10)), Chars, None)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_length_at_bounds() {
+        assert!(
+            "a".validate_length(Some(Inclusive(1)), None, Chars, None)
+                .is_ok()
+        );
+        assert!(
+            "a".validate_length(Some(Exclusive(1)), None, Chars, None)
+                .is_err()
+        );
+        assert!(
+            "abcd"
+                .validate_length(None, Some(Inclusive(4)), Chars, None)
+                .is_ok()
+        );
+        assert!(
+            "abcd"
+                .validate_length(None, Some(Exclusive(4)), Chars, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_length_records_failed_bound() {
+        let err = "abc"
+            .validate_length(Some(Equal(4)), None, Chars, None)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidLength {
+                kind: BoundKind::Equal,
+                limit: 4
+            }
+        ));
+    }
+
+    #[test]
+    fn test_validate_length_units() {
+        let flag = "\u{1F1EB}\u{1F1F7}";
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+        assert!(
+            flag.validate_length(None, Some(Inclusive(8)), Bytes, None)
+                .is_ok()
+        );
+        assert!(
+            flag.validate_length(None, Some(Inclusive(7)), Bytes, None)
+                .is_err()
+        );
+        assert!(
+            family
+                .validate_length(None, Some(Inclusive(5)), Chars, None)
+                .is_ok()
+        );
+        assert!(
+            family
+                .validate_length(None, Some(Inclusive(4)), Chars, None)
+                .is_err()
+        );
+        assert!(
+            family
+                .validate_length(Some(Equal(1)), None, Graphemes, None)
+                .is_ok()
+        );
+        assert!(
+            "h\u{e9}"
+                .validate_length(Some(Equal(2)), None, Utf16, None)
+                .is_ok()
+        );
+        assert!(
+            flag.validate_length(Some(Equal(4)), None, Utf16, None)
+                .is_ok()
+        );
     }
 }
@@ -1,18 +1,19 @@
 use std::borrow::Cow;
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateLowercase {
     fn validate_lowercase(&self, msg: Option<String>) -> Result<()> {
-        let err = msg.map(Error::Custom).unwrap_or(Error::Lowercase);
+        let err = Error::Lowercase.with_message(msg);
 
         let Some(str) = self.str() else {
-            return Err(err);
+            return Ok(());
         };
 
         match str.chars().all(|c| c.is_lowercase()) {
             true => Ok(()),
-            false => return Err(err),
+            false => Err(err),
         }
     }
 
@@ -55,6 +56,20 @@ impl<T: ValidateLowercase> ValidateLowercase for &T {
     }
 }
 
+impl<T: ValidateLowercase> ValidateLowercase for Patch<T> {
+    fn validate_lowercase(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_lowercase(msg),
+            Patch::Value(value) => value.validate_lowercase(msg),
+        }
+    }
+
+    fn str(&self) -> Option<&str> {
+        self.value().and_then(|s| s.str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,7 +1,9 @@
 pub mod alphanumeric;
 pub mod alphapetic;
 pub mod ascii;
+pub mod bound;
 pub mod color;
+pub mod compare;
 pub mod contains;
 pub mod email;
 pub mod ip;
@@ -10,6 +12,7 @@ pub mod lowercase;
 pub mod negative;
 pub mod phone;
 pub mod positive;
+pub mod presence;
 pub mod range;
 pub mod regex;
 pub mod required;
@@ -22,6 +25,51 @@ pub mod uppercase;
 // pub mod whitespace;
 // pub mod control;
 
+/// Every rule passes on `None` and `Patch::Null`, only `required` rejects them. The
+/// `none = reject` modifier reads this trait to fail the rules of one attribute on a
+/// missing value instead.
+pub trait Optional {
+    fn is_none(&self) -> bool;
+}
+
+impl<T> Optional for Option<T> {
+    fn is_none(&self) -> bool {
+        Option::is_none(self)
+    }
+}
+
+impl<T> Optional for crate::patch::Patch<T> {
+    fn is_none(&self) -> bool {
+        !self.is_value()
+    }
+}
+
+/// Lets generated code ask whether a field of any type is missing: `Option` and
+/// `Patch` answer through [`Optional`], every other type is never missing. Written as
+/// `(&MaybeOptional(&value)).is_missing()` with both traits in scope.
+#[doc(hidden)]
+pub struct MaybeOptional<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait IsOptional {
+    fn is_missing(&self) -> bool;
+}
+
+impl<T: Optional + ?Sized> IsOptional for MaybeOptional<'_, T> {
+    fn is_missing(&self) -> bool {
+        Optional::is_none(self.0)
+    }
+}
+
+#[doc(hidden)]
+pub trait IsPresent {
+    fn is_missing(&self) -> bool {
+        false
+    }
+}
+
+impl<T: ?Sized> IsPresent for &MaybeOptional<'_, T> {}
+
 pub trait AsStr {
     fn as_str(&self) -> &str;
 }
@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateNegative
 where
@@ -8,7 +9,7 @@ where
 {
     fn validate_negative(&self, msg: Option<String>) -> Result<()> {
         if *self >= Self::zero() {
-            return Err(msg.map(Error::Custom).unwrap_or(Error::Negative));
+            return Err(Error::Negative.with_message(msg));
         }
         Ok(())
     }
@@ -43,6 +44,32 @@ validate_numbers!(usize);
 validate_numbers!(f32);
 validate_numbers!(f64);
 
+impl<T: ValidateNegative> ValidateNegative for Option<T> {
+    fn validate_negative(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Some(value) => value.validate_negative(msg),
+            None => Ok(()),
+        }
+    }
+
+    fn zero() -> Self {
+        Some(T::zero())
+    }
+}
+
+impl<T: ValidateNegative> ValidateNegative for Patch<T> {
+    fn validate_negative(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Value(value) => value.validate_negative(msg),
+            Patch::Absent | Patch::Null => Ok(()),
+        }
+    }
+
+    fn zero() -> Self {
+        Patch::Value(T::zero())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::ValidateNegative;
@@ -1,28 +1,45 @@
-// use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::patch::Patch;
+use crate::rules::AsStr;
 
-use std::borrow::Cow;
+pub trait ValidatePhone {
+    fn validate_phone(&self, msg: Option<String>) -> Result<()> {
+        let Some(phone_str) = self.phone_str() else {
+            return Ok(());
+        };
 
-use crate::rules::AsStr;
+        phonenumber::parse(None, phone_str).map_err(|_| Error::Phone.with_message(msg))?;
+        Ok(())
+    }
 
-#[derive(Debug, thiserror::Error, Clone)]
-pub enum PhoneError {
-    #[error("Invalid phone number")]
-    Invalid,
-    #[error("{0}")]
-    Custom(String),
+    fn phone_str(&self) -> Option<&str>;
 }
 
-pub trait ValidatePhone: AsStr {
-    fn validate_phone(&self, msg: Option<String>) -> Result<(), PhoneError> {
-        let err = msg.map(PhoneError::Custom).unwrap_or(PhoneError::Invalid);
-        let phone_str = self.as_str();
+impl<T: AsStr> ValidatePhone for T {
+    fn phone_str(&self) -> Option<&str> {
+        Some(self.as_str())
+    }
+}
 
-        phonenumber::parse(None, phone_str).map_err(|_| err)?;
-        Ok(())
+impl<T: AsStr> ValidatePhone for Option<T> {
+    fn phone_str(&self) -> Option<&str> {
+        self.as_ref().map(|s| s.as_str())
     }
 }
 
-impl<T: AsStr> ValidatePhone for T {}
+impl<T: AsStr> ValidatePhone for Patch<T> {
+    fn validate_phone(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => Ok(()),
+            Patch::Value(value) => value.validate_phone(msg),
+        }
+    }
+
+    fn phone_str(&self) -> Option<&str> {
+        self.value().map(|s| s.as_str())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,69 @@
+use crate::{
+    error::{Error, Result},
+    rules::required::ValidateRequired,
+};
+
+/// How many fields of a group must be present. A field is present when
+/// `ValidateRequired::empty` returns `false`.
+pub enum Presence {
+    /// At least one field is present.
+    OneOfRequired,
+    /// At most one field is present.
+    MutuallyExclusive,
+    /// Exactly one field is present.
+    ExactlyOne,
+}
+
+pub fn validate_presence(
+    presence: Presence,
+    fields: &[(&str, &dyn ValidateRequired)],
+    msg: Option<String>,
+) -> Result<()> {
+    let count = fields.iter().filter(|(_, value)| !value.empty()).count();
+    let names = || fields.iter().map(|(name, _)| name.to_string()).collect();
+
+    let err = match presence {
+        Presence::OneOfRequired if count == 0 => Error::OneOfRequired { fields: names() },
+        Presence::MutuallyExclusive if count > 1 => Error::MutuallyExclusive { fields: names() },
+        Presence::ExactlyOne if count != 1 => Error::ExactlyOne { fields: names() },
+        _ => return Ok(()),
+    };
+
+    Err(err.with_message(msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Presence, validate_presence};
+
+    #[test]
+    fn test_one_of_required() {
+        let email = Some("test@gmail.com");
+        let phone: Option<&str> = None;
+
+        let fields = [("email", &email as _), ("phone", &phone as _)];
+        assert!(validate_presence(Presence::OneOfRequired, &fields, None).is_ok());
+
+        let fields = [("email", &None::<&str> as _), ("phone", &phone as _)];
+        assert!(validate_presence(Presence::OneOfRequired, &fields, None).is_err());
+    }
+
+    #[test]
+    fn test_mutually_exclusive_and_exactly_one() {
+        let card = "4242".to_string();
+        let iban = "DE89".to_string();
+        let empty = String::new();
+
+        let fields = [("card", &card as _), ("iban", &iban as _)];
+        assert!(validate_presence(Presence::MutuallyExclusive, &fields, None).is_err());
+        assert!(validate_presence(Presence::ExactlyOne, &fields, None).is_err());
+
+        let fields = [("card", &card as _), ("iban", &empty as _)];
+        assert!(validate_presence(Presence::MutuallyExclusive, &fields, None).is_ok());
+        assert!(validate_presence(Presence::ExactlyOne, &fields, None).is_ok());
+
+        let fields = [("card", &empty as _), ("iban", &empty as _)];
+        assert!(validate_presence(Presence::MutuallyExclusive, &fields, None).is_ok());
+        assert!(validate_presence(Presence::ExactlyOne, &fields, None).is_err());
+    }
+}
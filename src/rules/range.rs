@@ -2,89 +2,144 @@ use std::{
     cell::{Ref, RefMut},
     rc::Rc,
     sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::patch::Patch;
+use crate::rules::bound::Bound;
 
+/// Checks that a value lies within `min` and `max`, bounds of the value's own type.
+///
+/// Any ordered type works: integers, floats, `Duration`, `SystemTime`, and with the
+/// `rust_decimal` and `chrono` features `Decimal` and the chrono dates and times. A
+/// NaN float fails every bound. Other types opt in with [`impl_validate_range!`].
+///
+/// [`impl_validate_range!`]: crate::impl_validate_range
 pub trait ValidateRange {
+    type Value: PartialOrd;
+
     fn validate_range(
         &self,
-        min: Option<usize>,
-        max: Option<usize>,
+        min: Option<Bound<Self::Value>>,
+        max: Option<Bound<Self::Value>>,
         msg: Option<String>,
     ) -> Result<()> {
-        match (min, max, msg) {
-            (Some(_), Some(_), Some(msg)) => {
-                if self.value() < min || self.value() > max {
-                    return Err(crate::error::Error::Custom(msg));
-                }
-            }
-            (Some(_), Some(_), None) => {
-                if self.value() < min || self.value() > max {
-                    return Err(crate::error::Error::InvalidLength { min, max });
-                }
-            }
-            (Some(_), None, Some(msg)) => {
-                if self.value() < min {
-                    return Err(crate::error::Error::Custom(msg));
-                }
-            }
-            (Some(_), None, None) => {
-                if self.value() < min {
-                    return Err(crate::error::Error::InvalidLength { min, max });
-                }
-            }
-            (None, Some(_), Some(msg)) => {
-                if self.value() > max {
-                    return Err(crate::error::Error::Custom(msg));
-                }
-            }
-            (None, Some(_), None) => {
-                if self.value() > max {
-                    return Err(crate::error::Error::InvalidLength { min, max });
-                }
-            }
-            (None, None, None) | (None, None, Some(_)) => (),
+        let Some(value) = self.value() else {
+            return Ok(());
+        };
+        let failed = match (min, max) {
+            (Some(min), _) if !min.allows_min(&value) => Some((min.min_kind(), min)),
+            (_, Some(max)) if !max.allows_max(&value) => Some((max.max_kind(), max)),
+            _ => None,
+        };
+
+        if let Some((kind, bound)) = failed {
+            let limit = Self::limit(bound.value());
+            return Err(Error::OutOfRange { kind, limit }.with_message(msg));
         }
 
         Ok(())
     }
 
-    fn value(&self) -> Option<usize>;
+    fn value(&self) -> Option<Self::Value>;
+
+    /// Renders a bound as the `limit` of [`Error::OutOfRange`].
+    fn limit(bound: &Self::Value) -> String;
 }
 
-macro_rules! validate_num {
-    ($type:ty) => {
-        impl ValidateRange for $type {
-            fn value(&self) -> Option<usize> {
-                Some(*self as usize)
+/// Implements [`ValidateRange`] for ordered types that are their own bound, e.g.
+/// `validrs::impl_validate_range!(Money);` for `rng(min = Money(0))` on a
+/// `Money` field. Bounds are rendered with `Display`.
+///
+/// [`ValidateRange`]: crate::rules::range::ValidateRange
+#[macro_export]
+macro_rules! impl_validate_range {
+    ($($type:ty),+ $(,)?) => {
+        $(
+            impl $crate::rules::range::ValidateRange for $type {
+                type Value = $type;
+
+                fn value(&self) -> Option<$type> {
+                    Some(Clone::clone(self))
+                }
+
+                fn limit(bound: &$type) -> String {
+                    ToString::to_string(bound)
+                }
             }
-        }
+        )+
     };
 }
 
-validate_num!(i8);
-validate_num!(u8);
-validate_num!(i16);
-validate_num!(u16);
-validate_num!(i32);
-validate_num!(u32);
-validate_num!(i64);
-validate_num!(u64);
-validate_num!(i128);
-validate_num!(u128);
-validate_num!(usize);
+impl_validate_range!(
+    i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize, f32, f64,
+);
+
+impl ValidateRange for Duration {
+    type Value = Duration;
+
+    fn value(&self) -> Option<Duration> {
+        Some(*self)
+    }
+
+    fn limit(bound: &Duration) -> String {
+        format!("{bound:?}")
+    }
+}
+
+/// Bounds render as seconds since the Unix epoch.
+impl ValidateRange for SystemTime {
+    type Value = SystemTime;
+
+    fn value(&self) -> Option<SystemTime> {
+        Some(*self)
+    }
+
+    fn limit(bound: &SystemTime) -> String {
+        match bound.duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_secs_f64().to_string(),
+            Err(before) => (-before.duration().as_secs_f64()).to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl_validate_range!(rust_decimal::Decimal);
+
+#[cfg(feature = "chrono")]
+impl_validate_range!(chrono::NaiveDate, chrono::NaiveTime, chrono::NaiveDateTime);
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ValidateRange for chrono::DateTime<Tz> {
+    type Value = chrono::DateTime<Tz>;
+
+    fn value(&self) -> Option<Self::Value> {
+        Some(self.clone())
+    }
+
+    fn limit(bound: &Self::Value) -> String {
+        bound.to_utc().to_string()
+    }
+}
 
 macro_rules! validate_type_with_deref {
     ($type:ty) => {
         impl<T: ValidateRange> ValidateRange for $type {
-            fn value(&self) -> Option<usize> {
+            type Value = T::Value;
+
+            fn value(&self) -> Option<T::Value> {
                 T::value(self)
             }
+
+            fn limit(bound: &T::Value) -> String {
+                T::limit(bound)
+            }
         }
     };
 }
 
+validate_type_with_deref!(&T);
 validate_type_with_deref!(Box<T>);
 validate_type_with_deref!(Arc<T>);
 validate_type_with_deref!(Rc<T>);
@@ -92,22 +147,161 @@ validate_type_with_deref!(Ref<'_, T>);
 validate_type_with_deref!(RefMut<'_, T>);
 
 impl<T: ValidateRange> ValidateRange for Option<T> {
-    fn value(&self) -> Option<usize> {
-        let Some(s) = self else {
-            return None;
-        };
+    type Value = T::Value;
+
+    fn value(&self) -> Option<T::Value> {
+        self.as_ref().and_then(T::value)
+    }
+
+    fn limit(bound: &T::Value) -> String {
+        T::limit(bound)
+    }
+}
+
+impl<T: ValidateRange> ValidateRange for Patch<T> {
+    type Value = T::Value;
+
+    fn validate_range(
+        &self,
+        min: Option<Bound<T::Value>>,
+        max: Option<Bound<T::Value>>,
+        msg: Option<String>,
+    ) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_range(min, max, msg),
+            Patch::Value(value) => value.validate_range(min, max, msg),
+        }
+    }
 
-        T::value(s)
+    fn value(&self) -> Option<T::Value> {
+        Patch::value(self).and_then(|s| s.value())
+    }
+
+    fn limit(bound: &T::Value) -> String {
+        T::limit(bound)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::{Duration, SystemTime};
+
     use super::ValidateRange;
+    use crate::error::Error;
+    use crate::rules::bound::Bound::{Exclusive, Inclusive};
+    use crate::rules::bound::BoundKind;
+
+    #[test]
+    fn test_validate_range() {
+        assert!(
+            5.validate_range(Some(Inclusive(1)), Some(Inclusive(10)), None)
+                .is_ok()
+        );
+        assert!(
+            11.validate_range(Some(Inclusive(1)), Some(Inclusive(10)), None)
+                .is_err()
+        );
+        assert!(10.validate_range(None, Some(Exclusive(10)), None).is_err());
+    }
+
+    #[test]
+    fn test_validate_signed_and_float_range() {
+        assert!(
+            (-5i32)
+                .validate_range(Some(Inclusive(-10)), Some(Inclusive(0)), None)
+                .is_ok()
+        );
+        assert!(
+            (-5i32)
+                .validate_range(Some(Inclusive(0)), None, None)
+                .is_err()
+        );
+        assert!(
+            0.25f64
+                .validate_range(None, Some(Exclusive(0.5)), None)
+                .is_ok()
+        );
+        assert!(
+            0.5f64
+                .validate_range(None, Some(Exclusive(0.5)), None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_range_records_failed_bound() {
+        let err = 0.5f64
+            .validate_range(Some(Inclusive(0.0)), Some(Exclusive(0.5)), None)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::OutOfRange {
+                kind: BoundKind::ExclusiveMax,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Value is out of range: must be less than 0.5"
+        );
+    }
+
+    #[test]
+    fn test_validate_nan_range() {
+        assert!(
+            f64::NAN
+                .validate_range(Some(Inclusive(0.0)), None, None)
+                .is_err()
+        );
+        assert!(
+            f32::NAN
+                .validate_range(None, Some(Inclusive(1.0)), None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_duration_range() {
+        let max = Some(Inclusive(Duration::from_secs(30)));
+        assert!(
+            Duration::from_secs(30)
+                .validate_range(None, max, None)
+                .is_ok()
+        );
+        assert!(
+            Duration::from_secs(31)
+                .validate_range(None, max, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_time_limits() {
+        let err = Duration::from_millis(1500)
+            .validate_range(None, Some(Inclusive(Duration::from_secs(1))), None)
+            .unwrap_err();
+        assert!(matches!(err, Error::OutOfRange { limit, .. } if limit == "1s"));
+
+        let epoch = SystemTime::UNIX_EPOCH;
+        let min = Some(Inclusive(epoch + Duration::from_secs(60)));
+        let err = epoch.validate_range(min, None, None).unwrap_err();
+        assert!(matches!(err, Error::OutOfRange { limit, .. } if limit == "60"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_validate_date_range() {
+        let min = chrono::NaiveDate::from_ymd_opt(2000, 1, 1).map(Inclusive);
+        let date = chrono::NaiveDate::from_ymd_opt(1999, 12, 31).unwrap();
+        assert!(date.validate_range(min, None, None).is_err());
+    }
 
+    #[cfg(feature = "rust_decimal")]
     #[test]
-    fn test_validate_length() {
-        let valid = 5.validate_range(Some(1), Some(10), None).unwrap();
-        assert_eq!(valid, ());
+    fn test_validate_decimal_range() {
+        let price = rust_decimal::Decimal::new(1999, 2);
+        let max = Some(Exclusive(rust_decimal::Decimal::new(20, 0)));
+        assert!(price.validate_range(None, max, None).is_ok());
     }
 }
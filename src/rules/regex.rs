@@ -1,33 +1,27 @@
-use regex::Regex;
+pub use regex::Regex;
 use std::borrow::Cow;
-use thiserror::Error;
 
-#[derive(Debug, Error, Clone)]
-pub enum RegexError {
-    #[error("String doesn't match the pattern")]
-    NoMatch,
-
-    #[error("Invalid regex pattern: {0}")]
-    InvalidPattern(String),
-
-    #[error("{0}")]
-    Custom(String),
-}
-
-pub type RegexResult<T> = Result<T, RegexError>;
+use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateRegex {
-    fn validate_regex(&self, regex: &Regex, msg: Option<String>) -> RegexResult<()> {
-        let err = msg.map(RegexError::Custom).unwrap_or(RegexError::NoMatch);
-        let s = self.regex_str().ok_or(err.clone())?;
+    fn validate_regex(&self, regex: &Regex, msg: Option<String>) -> Result<()> {
+        let Some(s) = self.regex_str() else {
+            return Ok(());
+        };
         if !regex.is_match(s) {
-            return Err(err);
+            return Err(Error::NoMatch {
+                pattern: regex.as_str().to_string(),
+            }
+            .with_message(msg));
         }
         Ok(())
     }
 
-    fn validate_regex_pattern(&self, pattern: &str, msg: Option<String>) -> RegexResult<()> {
-        let regex = Regex::new(pattern).map_err(|e| RegexError::InvalidPattern(e.to_string()))?;
+    fn validate_regex_pattern(&self, pattern: &str, msg: Option<String>) -> Result<()> {
+        let regex = Regex::new(pattern).map_err(|e| Error::InvalidPattern {
+            error: e.to_string(),
+        })?;
         self.validate_regex(&regex, msg)
     }
 
@@ -64,6 +58,20 @@ impl<T: ValidateRegex> ValidateRegex for Option<T> {
     }
 }
 
+impl<T: ValidateRegex> ValidateRegex for Patch<T> {
+    fn validate_regex(&self, regex: &Regex, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_regex(regex, msg),
+            Patch::Value(value) => value.validate_regex(regex, msg),
+        }
+    }
+
+    fn regex_str(&self) -> Option<&str> {
+        self.value().and_then(|s| s.regex_str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::LazyLock;
@@ -97,7 +105,8 @@ mod tests {
         let err = "invalid"
             .validate_regex_pattern(r"\d+", Some("Custom error".into()))
             .unwrap_err();
-        assert!(matches!(err, RegexError::Custom(_)));
+        assert!(matches!(err.kind(), Error::NoMatch { .. }));
+        assert_eq!(err.to_string(), "Custom error");
 
         // Проверка с lazy_static regex
         assert!(
@@ -111,7 +120,7 @@ mod tests {
     #[test]
     fn test_invalid_pattern() {
         let result = "test".validate_regex_pattern(r"invalid[", None);
-        assert!(matches!(result, Err(RegexError::InvalidPattern(_))));
+        assert!(matches!(result, Err(Error::InvalidPattern { .. })));
     }
 
     #[test]
@@ -126,7 +135,7 @@ mod tests {
                 .validate_regex(&TEST_REGEX, None)
                 .is_ok()
         );
-        assert!(None::<&str>.validate_regex(&TEST_REGEX, None).is_err());
+        assert!(None::<&str>.validate_regex(&TEST_REGEX, None).is_ok());
         assert!(
             Cow::Borrowed("123-45-6789")
                 .validate_regex(&TEST_REGEX, None)
@@ -1,14 +1,12 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateRequired {
     fn validate_required(&self, msg: Option<String>) -> Result<()> {
         if self.empty() {
-            return match msg {
-                Some(msg) => Err(Error::Custom(msg)),
-                None => Err(Error::Required),
-            };
+            return Err(Error::Required.with_message(msg));
         }
         Ok(())
     }
@@ -51,6 +49,20 @@ impl<T> ValidateRequired for Option<T> {
     }
 }
 
+impl<T> ValidateRequired for Patch<T> {
+    fn validate_required(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => Err(Error::Required.with_message(msg)),
+            Patch::Value(_) => Ok(()),
+        }
+    }
+
+    fn empty(&self) -> bool {
+        !self.is_value()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
@@ -59,27 +71,23 @@ mod test {
 
     #[test]
     fn test_validate_option() {
-        let valid = Some(1).validate_required(None).unwrap();
-        assert_eq!(valid, ())
+        assert!(Some(1).validate_required(None).is_ok());
     }
 
     #[test]
     fn test_validate_str() {
-        let valid = "abc".validate_required(None).unwrap();
-        assert_eq!(valid, ())
+        assert!("abc".validate_required(None).is_ok());
     }
 
     #[test]
     fn test_validate_vec() {
-        let valid = vec![1].validate_required(None).unwrap();
-        assert_eq!(valid, ())
+        assert!(vec![1].validate_required(None).is_ok());
     }
 
     #[test]
     fn test_validate_hashmap() {
         let mut map = HashMap::new();
         map.insert(1, "a");
-        let valid = map.validate_required(None).unwrap();
-        assert_eq!(valid, ())
+        assert!(map.validate_required(None).is_ok());
     }
 }
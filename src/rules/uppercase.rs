@@ -1,18 +1,19 @@
 use std::borrow::Cow;
 
 use crate::error::{Error, Result};
+use crate::patch::Patch;
 
 pub trait ValidateUppercase {
     fn validate_uppercase(&self, msg: Option<String>) -> Result<()> {
-        let err = msg.map(Error::Custom).unwrap_or(Error::Lowercase);
+        let err = Error::Uppercase.with_message(msg);
 
         let Some(str) = self.str() else {
-            return Err(err);
+            return Ok(());
         };
 
         match str.chars().all(|c| c.is_uppercase()) {
             true => Ok(()),
-            false => return Err(err),
+            false => Err(err),
         }
     }
 
@@ -55,6 +56,20 @@ impl<T: ValidateUppercase> ValidateUppercase for &T {
     }
 }
 
+impl<T: ValidateUppercase> ValidateUppercase for Patch<T> {
+    fn validate_uppercase(&self, msg: Option<String>) -> Result<()> {
+        match self {
+            Patch::Absent => Ok(()),
+            Patch::Null => None::<T>.validate_uppercase(msg),
+            Patch::Value(value) => value.validate_uppercase(msg),
+        }
+    }
+
+    fn str(&self) -> Option<&str> {
+        self.value().and_then(|s| s.str())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
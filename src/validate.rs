@@ -1,5 +1,396 @@
-use crate::error::Result;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fmt::Display,
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::{error::ValidationErrors, patch::Patch, path::Path};
+
+/// Validation profile selecting which rules run, e.g. `#[valid(len(min = 1), groups = [create])]`.
+///
+/// Rules declared without `groups` belong to [`Group::Default`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Group {
+    Default,
+    Create,
+    Update,
+    Custom(&'static str),
+}
 
 pub trait Validate {
-    fn validate(&self) -> Result<()>;
+    /// Runs every rule and reports all failures instead of stopping at the first one.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+
+    /// Runs only the rules tagged with `group`.
+    fn validate_group(&self, group: Group) -> Result<(), ValidationErrors> {
+        match group {
+            Group::Default => self.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports only the errors of the listed top-level fields, e.g. the ones sent in a
+    /// PATCH body.
+    ///
+    /// This default runs every rule and keeps the errors of `fields`, dropping the ones
+    /// reported on the value itself. `#[derive(Valid)]` runs only the rules of the listed
+    /// fields instead, for enums the fields of the active variant. Struct-level rules
+    /// such as `schema` and variant-level ones such as `reject` do not run.
+    fn validate_fields(&self, fields: &[&str]) -> Result<(), ValidationErrors> {
+        self.validate().or_else(|mut errors| {
+            errors.retain_fields(fields);
+            errors.into_result()
+        })
+    }
+}
+
+/// Validation that needs runtime data such as plan limits or a clock.
+///
+/// `#[derive(Valid)]` implements it for every context when the struct needs none,
+/// so such structs can be nested into ones declared with `#[valid(context = MyCtx)]`.
+pub trait ValidateWithContext<C: ?Sized> {
+    fn validate_with_context(&self, ctx: &C) -> Result<(), ValidationErrors>;
+
+    /// Runs only the rules tagged with `group`.
+    fn validate_group_with_context(&self, ctx: &C, group: Group) -> Result<(), ValidationErrors> {
+        match group {
+            Group::Default => self.validate_with_context(ctx),
+            _ => Ok(()),
+        }
+    }
+
+    /// Same as [`Validate::validate_fields`], with a context.
+    fn validate_fields_with_context(
+        &self,
+        ctx: &C,
+        fields: &[&str],
+    ) -> Result<(), ValidationErrors> {
+        self.validate_with_context(ctx).or_else(|mut errors| {
+            errors.retain_fields(fields);
+            errors.into_result()
+        })
+    }
+}
+
+/// Validation with rules that need an async lookup, e.g. "username not already taken".
+///
+/// `#[derive(Valid)]` puts `#[valid(async_custom(...))]` rules and the async rules of
+/// nested fields into [`AsyncValidate::validate_async_rules`].
+pub trait AsyncValidate<C: ?Sized = ()> {
+    /// Runs only the async rules tagged with `group`.
+    fn validate_async_rules(
+        &self,
+        ctx: &C,
+        group: Group,
+    ) -> impl Future<Output = Result<(), ValidationErrors>>;
+
+    /// Runs the sync rules first and merges the async rules into the same report.
+    fn validate_async(&self, ctx: &C) -> impl Future<Output = Result<(), ValidationErrors>>
+    where
+        Self: ValidateWithContext<C>,
+    {
+        self.validate_async_group(ctx, Group::Default)
+    }
+
+    /// Same as [`AsyncValidate::validate_async`], limited to the rules tagged with `group`.
+    fn validate_async_group(
+        &self,
+        ctx: &C,
+        group: Group,
+    ) -> impl Future<Output = Result<(), ValidationErrors>>
+    where
+        Self: ValidateWithContext<C>,
+    {
+        async move {
+            let mut errors = match self.validate_group_with_context(ctx, group) {
+                Ok(()) => ValidationErrors::new(),
+                Err(errors) => errors,
+            };
+            if let Err(inner) = self.validate_async_rules(ctx, group).await {
+                errors.merge(Path::root(), inner);
+            }
+            errors.into_result()
+        }
+    }
+}
+
+macro_rules! validate_type_with_deref {
+    ($type:ty) => {
+        impl<T: Validate + ?Sized> Validate for $type {
+            fn validate(&self) -> Result<(), ValidationErrors> {
+                T::validate(self)
+            }
+
+            fn validate_group(&self, group: Group) -> Result<(), ValidationErrors> {
+                T::validate_group(self, group)
+            }
+
+            fn validate_fields(&self, fields: &[&str]) -> Result<(), ValidationErrors> {
+                T::validate_fields(self, fields)
+            }
+        }
+
+        impl<C: ?Sized, T: ValidateWithContext<C> + ?Sized> ValidateWithContext<C> for $type {
+            fn validate_with_context(&self, ctx: &C) -> Result<(), ValidationErrors> {
+                T::validate_with_context(self, ctx)
+            }
+
+            fn validate_group_with_context(
+                &self,
+                ctx: &C,
+                group: Group,
+            ) -> Result<(), ValidationErrors> {
+                T::validate_group_with_context(self, ctx, group)
+            }
+
+            fn validate_fields_with_context(
+                &self,
+                ctx: &C,
+                fields: &[&str],
+            ) -> Result<(), ValidationErrors> {
+                T::validate_fields_with_context(self, ctx, fields)
+            }
+        }
+
+        impl<C: ?Sized, T: AsyncValidate<C> + ?Sized> AsyncValidate<C> for $type {
+            async fn validate_async_rules(
+                &self,
+                ctx: &C,
+                group: Group,
+            ) -> Result<(), ValidationErrors> {
+                T::validate_async_rules(self, ctx, group).await
+            }
+        }
+    };
+}
+
+validate_type_with_deref!(&T);
+validate_type_with_deref!(Box<T>);
+validate_type_with_deref!(Arc<T>);
+validate_type_with_deref!(Rc<T>);
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.validate_group(Group::Default)
+    }
+
+    fn validate_group(&self, group: Group) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate_group(group),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_fields(&self, fields: &[&str]) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate_fields(fields),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<C: ?Sized, T: ValidateWithContext<C>> ValidateWithContext<C> for Option<T> {
+    fn validate_with_context(&self, ctx: &C) -> Result<(), ValidationErrors> {
+        self.validate_group_with_context(ctx, Group::Default)
+    }
+
+    fn validate_group_with_context(&self, ctx: &C, group: Group) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate_group_with_context(ctx, group),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_fields_with_context(
+        &self,
+        ctx: &C,
+        fields: &[&str],
+    ) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate_fields_with_context(ctx, fields),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<C: ?Sized, T: AsyncValidate<C>> AsyncValidate<C> for Option<T> {
+    async fn validate_async_rules(&self, ctx: &C, group: Group) -> Result<(), ValidationErrors> {
+        match self {
+            Some(value) => value.validate_async_rules(ctx, group).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Validate> Validate for Patch<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.validate_group(Group::Default)
+    }
+
+    fn validate_group(&self, group: Group) -> Result<(), ValidationErrors> {
+        match self {
+            Patch::Value(value) => value.validate_group(group),
+            Patch::Absent | Patch::Null => Ok(()),
+        }
+    }
+
+    fn validate_fields(&self, fields: &[&str]) -> Result<(), ValidationErrors> {
+        match self {
+            Patch::Value(value) => value.validate_fields(fields),
+            Patch::Absent | Patch::Null => Ok(()),
+        }
+    }
+}
+
+impl<C: ?Sized, T: ValidateWithContext<C>> ValidateWithContext<C> for Patch<T> {
+    fn validate_with_context(&self, ctx: &C) -> Result<(), ValidationErrors> {
+        self.validate_group_with_context(ctx, Group::Default)
+    }
+
+    fn validate_group_with_context(&self, ctx: &C, group: Group) -> Result<(), ValidationErrors> {
+        match self {
+            Patch::Value(value) => value.validate_group_with_context(ctx, group),
+            Patch::Absent | Patch::Null => Ok(()),
+        }
+    }
+
+    fn validate_fields_with_context(
+        &self,
+        ctx: &C,
+        fields: &[&str],
+    ) -> Result<(), ValidationErrors> {
+        match self {
+            Patch::Value(value) => value.validate_fields_with_context(ctx, fields),
+            Patch::Absent | Patch::Null => Ok(()),
+        }
+    }
+}
+
+impl<C: ?Sized, T: AsyncValidate<C>> AsyncValidate<C> for Patch<T> {
+    async fn validate_async_rules(&self, ctx: &C, group: Group) -> Result<(), ValidationErrors> {
+        match self {
+            Patch::Value(value) => value.validate_async_rules(ctx, group).await,
+            Patch::Absent | Patch::Null => Ok(()),
+        }
+    }
 }
+
+macro_rules! validate_type_with_iter {
+    ($type:ty) => {
+        impl<T: Validate> Validate for $type {
+            fn validate(&self) -> Result<(), ValidationErrors> {
+                self.validate_group(Group::Default)
+            }
+
+            fn validate_group(&self, group: Group) -> Result<(), ValidationErrors> {
+                let mut errors = ValidationErrors::new();
+                for (index, value) in self.iter().enumerate() {
+                    if let Err(inner) = value.validate_group(group) {
+                        errors.merge(Path::index(index), inner);
+                    }
+                }
+                errors.into_result()
+            }
+        }
+
+        impl<C: ?Sized, T: ValidateWithContext<C>> ValidateWithContext<C> for $type {
+            fn validate_with_context(&self, ctx: &C) -> Result<(), ValidationErrors> {
+                self.validate_group_with_context(ctx, Group::Default)
+            }
+
+            fn validate_group_with_context(
+                &self,
+                ctx: &C,
+                group: Group,
+            ) -> Result<(), ValidationErrors> {
+                let mut errors = ValidationErrors::new();
+                for (index, value) in self.iter().enumerate() {
+                    if let Err(inner) = value.validate_group_with_context(ctx, group) {
+                        errors.merge(Path::index(index), inner);
+                    }
+                }
+                errors.into_result()
+            }
+        }
+
+        impl<C: ?Sized, T: AsyncValidate<C>> AsyncValidate<C> for $type {
+            async fn validate_async_rules(
+                &self,
+                ctx: &C,
+                group: Group,
+            ) -> Result<(), ValidationErrors> {
+                let mut errors = ValidationErrors::new();
+                for (index, value) in self.iter().enumerate() {
+                    if let Err(inner) = value.validate_async_rules(ctx, group).await {
+                        errors.merge(Path::index(index), inner);
+                    }
+                }
+                errors.into_result()
+            }
+        }
+    };
+}
+
+validate_type_with_iter!([T]);
+validate_type_with_iter!(Vec<T>);
+validate_type_with_iter!(VecDeque<T>);
+
+macro_rules! validate_type_with_values {
+    ($type:ty) => {
+        impl<K: Display, V: Validate> Validate for $type {
+            fn validate(&self) -> Result<(), ValidationErrors> {
+                self.validate_group(Group::Default)
+            }
+
+            fn validate_group(&self, group: Group) -> Result<(), ValidationErrors> {
+                let mut errors = ValidationErrors::new();
+                for (key, value) in self.iter() {
+                    if let Err(inner) = value.validate_group(group) {
+                        errors.merge(Path::key(key), inner);
+                    }
+                }
+                errors.into_result()
+            }
+        }
+
+        impl<C: ?Sized, K: Display, V: ValidateWithContext<C>> ValidateWithContext<C> for $type {
+            fn validate_with_context(&self, ctx: &C) -> Result<(), ValidationErrors> {
+                self.validate_group_with_context(ctx, Group::Default)
+            }
+
+            fn validate_group_with_context(
+                &self,
+                ctx: &C,
+                group: Group,
+            ) -> Result<(), ValidationErrors> {
+                let mut errors = ValidationErrors::new();
+                for (key, value) in self.iter() {
+                    if let Err(inner) = value.validate_group_with_context(ctx, group) {
+                        errors.merge(Path::key(key), inner);
+                    }
+                }
+                errors.into_result()
+            }
+        }
+
+        impl<C: ?Sized, K: Display, V: AsyncValidate<C>> AsyncValidate<C> for $type {
+            async fn validate_async_rules(
+                &self,
+                ctx: &C,
+                group: Group,
+            ) -> Result<(), ValidationErrors> {
+                let mut errors = ValidationErrors::new();
+                for (key, value) in self.iter() {
+                    if let Err(inner) = value.validate_async_rules(ctx, group).await {
+                        errors.merge(Path::key(key), inner);
+                    }
+                }
+                errors.into_result()
+            }
+        }
+    };
+}
+
+validate_type_with_values!(HashMap<K, V>);
+validate_type_with_values!(BTreeMap<K, V>);
@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use validrs::error::{Error, Result};
+use validrs::validate::AsyncValidate;
+use validrs_derive::Valid;
+
+/// In-memory stand-in for the user and coupon tables.
+struct Repository {
+    usernames: HashSet<&'static str>,
+    coupons: HashSet<&'static str>,
+}
+
+impl Repository {
+    async fn username_exists(&self, username: &str) -> bool {
+        self.usernames.contains(username)
+    }
+
+    async fn coupon_exists(&self, code: &str) -> bool {
+        self.coupons.contains(code)
+    }
+}
+
+async fn username_available(username: &str, repo: &Repository) -> Result<()> {
+    match repo.username_exists(username).await {
+        true => Err(Error::Custom(format!("`{username}` is already taken"))),
+        false => Ok(()),
+    }
+}
+
+async fn coupon_exists(code: &str, repo: &Repository) -> Result<()> {
+    match repo.coupon_exists(code).await {
+        true => Ok(()),
+        false => Err(Error::Custom("Unknown coupon".into())),
+    }
+}
+
+#[derive(Valid)]
+#[valid(context = Repository)]
+struct Coupon {
+    #[valid(async_custom(function = coupon_exists, args = ctx))]
+    code: String,
+}
+
+#[derive(Valid)]
+#[valid(context = Repository)]
+struct SignUp {
+    #[valid(len(min = 3, max = 16))]
+    #[valid(async_custom(function = username_available, args = ctx))]
+    username: String,
+
+    #[valid(nested)]
+    coupons: Vec<Coupon>,
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn repository() -> Repository {
+    Repository {
+        usernames: HashSet::from(["john"]),
+        coupons: HashSet::from(["WELCOME"]),
+    }
+}
+
+fn coupon(code: &str) -> Coupon {
+    Coupon {
+        code: code.to_string(),
+    }
+}
+
+#[test]
+fn test_async_rules_pass() {
+    let sign_up = SignUp {
+        username: "jane".to_string(),
+        coupons: vec![coupon("WELCOME")],
+    };
+
+    assert!(block_on(sign_up.validate_async(&repository())).is_ok());
+}
+
+#[test]
+fn test_async_rules_merge_with_sync_rules() {
+    let sign_up = SignUp {
+        username: "john".to_string(),
+        coupons: vec![coupon("WELCOME"), coupon("EXPIRED")],
+    };
+
+    let errors = block_on(sign_up.validate_async(&repository())).unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "coupons[1].code: Unknown coupon\nusername: `john` is already taken"
+    );
+
+    let sign_up = SignUp {
+        username: "jo".to_string(),
+        coupons: vec![],
+    };
+
+    let errors = block_on(sign_up.validate_async(&repository())).unwrap_err();
+    assert_eq!(errors.field("username").unwrap().len(), 1);
+}
+
+async fn label_known(label: &str, repo: &Repository) -> Result<()> {
+    match repo.coupon_exists(label).await {
+        true => Ok(()),
+        false => Err(Error::Custom("Unknown label".into())),
+    }
+}
+
+#[derive(Valid)]
+#[valid(context = Repository)]
+struct Tree {
+    #[valid(async_custom(function = label_known, args = ctx))]
+    label: String,
+
+    #[valid(nested)]
+    children: Vec<Tree>,
+}
+
+#[test]
+fn test_recursive_async_rules() {
+    let tree = Tree {
+        label: "WELCOME".to_string(),
+        children: vec![Tree {
+            label: "WELCOME".to_string(),
+            children: vec![Tree {
+                label: "EXPIRED".to_string(),
+                children: vec![],
+            }],
+        }],
+    };
+
+    let errors = block_on(tree.validate_async(&repository())).unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "children[0].children[0].label: Unknown label"
+    );
+}
+
+#[derive(Valid)]
+struct Menu {
+    #[valid(len(min = 1))]
+    title: String,
+
+    #[valid(nested)]
+    items: Vec<Menu>,
+}
+
+#[test]
+fn test_recursive_without_context() {
+    let menu = Menu {
+        title: "File".to_string(),
+        items: vec![Menu {
+            title: String::new(),
+            items: vec![],
+        }],
+    };
+
+    let errors = block_on(menu.validate_async(&())).unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["items[0].title"]);
+}
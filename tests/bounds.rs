@@ -0,0 +1,89 @@
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+mod limits {
+    pub const NAME_LEN: usize = 8;
+    pub const MIN_OFFSET: i32 = -12;
+}
+
+const MAX_RATIO: f64 = 0.5;
+
+#[derive(Valid)]
+struct Settings {
+    #[valid(len(min = 1, max = limits::NAME_LEN, msg = "At most {{max}} characters"))]
+    name: String,
+
+    #[valid(rng(min = limits::MIN_OFFSET, max = 14))]
+    offset: i32,
+
+    #[valid(rng(min = -10, max = 40))]
+    celsius: i16,
+
+    #[valid(rng(max = MAX_RATIO))]
+    ratio: f64,
+
+    #[valid(rng(min = 0.0, max = 2.0 * MAX_RATIO, msg = "Between {{min}} and {{max}}"))]
+    share: f64,
+
+    #[valid(rng(min = 0.50, max = 1_000.0, msg = "From {{min}} to {{max}}"))]
+    weight: f64,
+}
+
+fn settings() -> Settings {
+    Settings {
+        name: "main".to_string(),
+        offset: -3,
+        celsius: -5,
+        ratio: 0.25,
+        share: 0.5,
+        weight: 1.0,
+    }
+}
+
+#[test]
+fn test_const_and_negative_bounds() {
+    assert!(settings().validate().is_ok());
+
+    let settings = Settings {
+        offset: -13,
+        celsius: -11,
+        ..settings()
+    };
+
+    let errors = settings.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["celsius", "offset"]);
+}
+
+#[test]
+fn test_float_bounds() {
+    let settings = Settings {
+        ratio: 0.75,
+        ..settings()
+    };
+    assert!(settings.validate().unwrap_err().field("ratio").is_some());
+}
+
+#[test]
+fn test_templates_with_constants() {
+    let settings = Settings {
+        name: "a very long name".to_string(),
+        share: 1.5,
+        weight: 0.25,
+        ..settings()
+    };
+
+    let errors = settings.validate().unwrap_err();
+    assert_eq!(
+        errors.field("name").unwrap()[0].to_string(),
+        "At most 8 characters"
+    );
+    assert_eq!(
+        errors.field("share").unwrap()[0].to_string(),
+        "Between 0 and 1"
+    );
+    assert_eq!(
+        errors.field("weight").unwrap()[0].to_string(),
+        "From 0.5 to 1000"
+    );
+}
@@ -0,0 +1,62 @@
+use validrs::error::Error;
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Registration {
+    password: String,
+
+    #[valid(must_match(other = "password", msg = "`{{field}}` must match `{{other}}`"))]
+    password_confirmation: String,
+}
+
+#[derive(Valid)]
+struct PriceFilter {
+    #[valid(lt_field = "max_price")]
+    min_price: f64,
+
+    #[valid(gt_field(other = "min_price"))]
+    max_price: f64,
+}
+
+#[test]
+fn test_must_match() {
+    let registration = Registration {
+        password: "secret".to_string(),
+        password_confirmation: "secret".to_string(),
+    };
+    assert!(registration.validate().is_ok());
+
+    let registration = Registration {
+        password: "secret".to_string(),
+        password_confirmation: "Secret".to_string(),
+    };
+    let errors = registration.validate().unwrap_err();
+    assert_eq!(
+        errors.field("password_confirmation").unwrap()[0].to_string(),
+        "`password_confirmation` must match `password`"
+    );
+}
+
+#[test]
+fn test_ordered_fields() {
+    let filter = PriceFilter {
+        min_price: 10.0,
+        max_price: 20.0,
+    };
+    assert!(filter.validate().is_ok());
+
+    let filter = PriceFilter {
+        min_price: 20.0,
+        max_price: 10.0,
+    };
+    let errors = filter.validate().unwrap_err();
+    assert!(matches!(
+        &errors.field("min_price").unwrap()[0],
+        Error::NotLess { other } if other == "max_price"
+    ));
+    assert!(matches!(
+        &errors.field("max_price").unwrap()[0],
+        Error::NotGreater { other } if other == "min_price"
+    ));
+}
@@ -0,0 +1,65 @@
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+#[derive(PartialEq)]
+enum AccountType {
+    Personal,
+    Business,
+}
+
+fn is_business(account: &Account) -> bool {
+    account.account_type == AccountType::Business
+}
+
+#[derive(Valid)]
+struct Account {
+    account_type: AccountType,
+
+    #[valid(required_if(field = "account_type", eq = AccountType::Business))]
+    company_name: Option<String>,
+
+    #[valid(len(min = 5, max = 15), when = is_business)]
+    vat_number: String,
+
+    country: String,
+
+    #[valid(required_unless(
+        field = "country",
+        eq = "US",
+        msg = "IBAN is required outside the US"
+    ))]
+    iban: String,
+}
+
+#[test]
+fn test_personal_account_skips_business_rules() {
+    let account = Account {
+        account_type: AccountType::Personal,
+        company_name: None,
+        vat_number: String::new(),
+        country: "US".to_string(),
+        iban: String::new(),
+    };
+
+    assert!(account.validate().is_ok());
+}
+
+#[test]
+fn test_business_account_requires_company() {
+    let account = Account {
+        account_type: AccountType::Business,
+        company_name: None,
+        vat_number: "1".to_string(),
+        country: "DE".to_string(),
+        iban: String::new(),
+    };
+
+    let errors = account.validate().unwrap_err();
+
+    assert!(errors.field("company_name").is_some());
+    assert!(errors.field("vat_number").is_some());
+    assert_eq!(
+        errors.field("iban").unwrap()[0].to_string(),
+        "IBAN is required outside the US"
+    );
+}
@@ -0,0 +1,69 @@
+use validrs::error::{Error, Result};
+use validrs::validate::ValidateWithContext;
+use validrs_derive::Valid;
+
+struct Plan {
+    max_projects: usize,
+    currencies: Vec<&'static str>,
+}
+
+fn within_plan(projects: &[Project], max: usize) -> Result<()> {
+    match projects.len() <= max {
+        true => Ok(()),
+        false => Err(Error::Custom(format!("Your plan allows {max} projects"))),
+    }
+}
+
+fn allowed_currency(currency: &String, plan: &Plan) -> Result<()> {
+    match plan.currencies.contains(&currency.as_str()) {
+        true => Ok(()),
+        false => Err(Error::Custom(format!("{currency} is not supported"))),
+    }
+}
+
+#[derive(Valid)]
+struct Project {
+    #[valid(required)]
+    name: String,
+}
+
+#[derive(Valid)]
+#[valid(context = Plan)]
+struct Workspace {
+    #[valid(custom(function = within_plan, args = ctx.max_projects), nested)]
+    projects: Vec<Project>,
+
+    #[valid(custom(function = allowed_currency, args = ctx))]
+    currency: String,
+}
+
+fn project(name: &str) -> Project {
+    Project {
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn test_context_is_passed_to_rules() {
+    let plan = Plan {
+        max_projects: 2,
+        currencies: vec!["EUR", "USD"],
+    };
+
+    let workspace = Workspace {
+        projects: vec![project("a"), project("b")],
+        currency: "EUR".to_string(),
+    };
+    assert!(workspace.validate_with_context(&plan).is_ok());
+
+    let workspace = Workspace {
+        projects: vec![project("a"), project(""), project("c")],
+        currency: "GBP".to_string(),
+    };
+    let errors = workspace.validate_with_context(&plan).unwrap_err();
+
+    assert_eq!(
+        errors.to_string(),
+        "currency: GBP is not supported\nprojects: Your plan allows 2 projects\nprojects[1].name: Field must be required"
+    );
+}
@@ -0,0 +1,70 @@
+use validrs::error::{Error, Result};
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+mod checks {
+    use validrs::error::{Error, Result};
+
+    pub fn not_reserved(name: &String) -> Result<()> {
+        match name.as_str() {
+            "admin" | "root" => Err(Error::Custom(format!("`{name}` is reserved"))),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn multiple_of(value: &u32, step: u32) -> Result<()> {
+    match value % step {
+        0 => Ok(()),
+        _ => Err(Error::Custom(format!("Must be a multiple of {step}"))),
+    }
+}
+
+fn within(value: &u32, min: u32, max: u32) -> Result<()> {
+    match (min..=max).contains(value) {
+        true => Ok(()),
+        false => Err(Error::Custom("Out of bounds".into())),
+    }
+}
+
+#[derive(Valid)]
+struct Account {
+    #[valid(custom(function = checks::not_reserved))]
+    name: String,
+
+    #[valid(custom(function = multiple_of, args = 5))]
+    quota: u32,
+
+    #[valid(custom(function = within, args = (1, 10), msg = "Pick 1 to 10 seats"))]
+    seats: u32,
+}
+
+#[test]
+fn test_custom_functions() {
+    let account = Account {
+        name: "john".to_string(),
+        quota: 15,
+        seats: 3,
+    };
+    assert!(account.validate().is_ok());
+
+    let account = Account {
+        name: "root".to_string(),
+        quota: 7,
+        seats: 11,
+    };
+    let errors = account.validate().unwrap_err();
+
+    assert_eq!(
+        errors.field("name").unwrap()[0].to_string(),
+        "`root` is reserved"
+    );
+    assert_eq!(
+        errors.field("quota").unwrap()[0].to_string(),
+        "Must be a multiple of 5"
+    );
+    assert_eq!(
+        errors.field("seats").unwrap()[0].to_string(),
+        "Pick 1 to 10 seats"
+    );
+}
@@ -0,0 +1,111 @@
+use validrs::error::Error;
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct SignUp {
+    #[valid(len(min = 1, max = 16), required)]
+    name: String,
+
+    #[valid(rng(min = 18, max = 120))]
+    age: usize,
+
+    #[valid(required(msg = "At least 1 role is required"))]
+    roles: Vec<String>,
+}
+
+#[test]
+fn test_collects_every_field_error() {
+    let form = SignUp {
+        name: String::new(),
+        age: 7,
+        roles: vec![],
+    };
+
+    let errors = form.validate().unwrap_err();
+
+    assert_eq!(errors.len(), 4);
+    assert_eq!(errors.field("name").unwrap().len(), 2);
+    assert_eq!(errors.field("age").unwrap().len(), 1);
+    assert_eq!(
+        errors.field("roles").unwrap()[0].to_string(),
+        "At least 1 role is required"
+    );
+}
+
+#[test]
+fn test_errors_carry_field_paths() {
+    let form = SignUp {
+        name: "John".to_string(),
+        age: 7,
+        roles: vec![],
+    };
+
+    let errors = form.validate().unwrap_err();
+    let pointers: Vec<_> = errors
+        .fields()
+        .map(|(path, _)| path.to_json_pointer())
+        .collect();
+
+    assert_eq!(pointers, ["/age", "/roles"]);
+}
+
+#[test]
+fn test_valid_struct_has_no_errors() {
+    let form = SignUp {
+        name: "John".to_string(),
+        age: 20,
+        roles: vec!["user".to_string()],
+    };
+
+    assert!(form.validate().is_ok());
+}
+
+#[derive(Valid)]
+#[valid(one_of_required(r#type, r#ref))]
+struct Keyword {
+    #[valid(len(min = 2))]
+    r#type: Option<String>,
+
+    #[valid(must_match = "type")]
+    r#ref: Option<String>,
+
+    #[valid(required_if(field = "type", eq = Some("alias".to_string())))]
+    r#target: Option<String>,
+}
+
+#[test]
+fn test_raw_identifiers_are_named_without_prefix() {
+    let keyword = Keyword {
+        r#type: Some("a".to_string()),
+        r#ref: Some("b".to_string()),
+        r#target: None,
+    };
+
+    let errors = keyword.validate().unwrap_err();
+    let paths: Vec<_> = errors
+        .fields()
+        .map(|(path, _)| path.to_json_pointer())
+        .collect();
+    assert_eq!(paths, ["/ref", "/type"]);
+    assert!(errors.field("type").is_some());
+
+    let keyword = Keyword {
+        r#type: Some("alias".to_string()),
+        r#ref: None,
+        r#target: None,
+    };
+    let errors = keyword.validate().unwrap_err();
+    assert!(errors.field("target").is_some());
+
+    let keyword = Keyword {
+        r#type: None,
+        r#ref: None,
+        r#target: None,
+    };
+    let errors = keyword.validate().unwrap_err();
+    assert!(matches!(
+        errors.field("type").unwrap(),
+        [Error::OneOfRequired { fields }] if fields == &["type", "ref"]
+    ));
+}
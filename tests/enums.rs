@@ -0,0 +1,98 @@
+use validrs::error::Error;
+use validrs::validate::{Group, Validate};
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Address {
+    #[valid(len(min = 4, max = 11))]
+    zip: String,
+}
+
+#[derive(Valid)]
+enum Payment {
+    Card {
+        #[valid(len(min = 12, max = 20))]
+        number: String,
+
+        #[valid(gt_field = "number")]
+        holder: String,
+    },
+    Transfer {
+        #[valid(required)]
+        iban: String,
+
+        #[valid(nested)]
+        address: Address,
+    },
+    #[valid(reject)]
+    Cheque,
+    #[valid(reject(msg = "Cash is only accepted on create"), groups = [update])]
+    Cash,
+}
+
+#[test]
+fn test_active_variant_is_validated() {
+    let payment = Payment::Card {
+        number: "1".to_string(),
+        holder: "A".to_string(),
+    };
+
+    let errors = payment.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["Card.number"]);
+}
+
+#[test]
+fn test_nested_fields_of_variant() {
+    let payment = Payment::Transfer {
+        iban: String::new(),
+        address: Address {
+            zip: "1".to_string(),
+        },
+    };
+
+    let errors = payment.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["Transfer.address.zip", "Transfer.iban"]);
+}
+
+#[test]
+fn test_rejected_variant() {
+    let errors = Payment::Cheque.validate().unwrap_err();
+    assert!(matches!(
+        errors.field("Cheque"),
+        Some([Error::RejectedVariant { variant }]) if variant == "Cheque"
+    ));
+
+    assert!(Payment::Cash.validate().is_ok());
+    let errors = Payment::Cash.validate_group(Group::Update).unwrap_err();
+    assert_eq!(
+        errors.field("Cash").unwrap()[0].to_string(),
+        "Cash is only accepted on create"
+    );
+}
+
+#[test]
+fn test_validate_fields_of_active_variant() {
+    let payment = Payment::Card {
+        number: "1".to_string(),
+        holder: "A".to_string(),
+    };
+
+    let errors = payment.validate_fields(&["number"]).unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["Card.number"]);
+
+    assert!(payment.validate_fields(&["holder"]).is_ok());
+    assert!(payment.validate_fields(&["Card"]).is_ok());
+
+    let payment = Payment::Transfer {
+        iban: String::new(),
+        address: Address {
+            zip: "1".to_string(),
+        },
+    };
+    let errors = payment.validate_fields(&["address"]).unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["Transfer.address.zip"]);
+}
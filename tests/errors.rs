@@ -0,0 +1,116 @@
+use validrs::error::{Error, ValidationError};
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Account {
+    #[valid(len(1..=16, msg = "Between {{min}} and {{max}} characters"))]
+    name: String,
+
+    #[valid(phone)]
+    phone: String,
+
+    #[valid(color(msg = "Pick a color"))]
+    theme: String,
+
+    #[valid(rng(min = 18))]
+    age: u8,
+}
+
+#[test]
+fn test_every_rule_reports_a_code() {
+    let account = Account {
+        name: String::new(),
+        phone: "call me".to_string(),
+        theme: "blue-ish".to_string(),
+        age: 17,
+    };
+
+    let errors = account.validate().unwrap_err();
+    let codes: Vec<_> = errors
+        .errors()
+        .map(|(path, error)| (path.to_string(), error.code()))
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            ("age".to_string(), "range"),
+            ("name".to_string(), "length"),
+            ("phone".to_string(), "phone"),
+            ("theme".to_string(), "color"),
+        ]
+    );
+}
+
+#[test]
+fn test_custom_message_keeps_code_and_params() {
+    let account = Account {
+        name: "a".repeat(17),
+        phone: "+14155552671".to_string(),
+        theme: "#fff".to_string(),
+        age: 30,
+    };
+
+    let errors = account.validate().unwrap_err();
+    let name = &errors.field("name").unwrap()[0];
+    assert!(matches!(
+        name.kind(),
+        Error::InvalidLength { limit: 16, .. }
+    ));
+
+    let generic: Vec<_> = errors.validation_errors().collect();
+    assert_eq!(
+        generic[0].1,
+        ValidationError {
+            code: "length",
+            message: Some("Between 1 and 16 characters".to_string()),
+            params: [("kind", "max".to_string()), ("limit", "16".to_string())].into(),
+        }
+    );
+}
+
+#[derive(Valid)]
+struct Server {
+    #[valid(ip(v6))]
+    address: String,
+
+    #[valid(color(hex))]
+    accent: String,
+
+    #[valid(regex(pattern = "^[a-z]+$"))]
+    slug: String,
+
+    #[valid(contains(["@", "."]))]
+    email: String,
+}
+
+#[test]
+fn test_rule_arguments_become_params() {
+    let server = Server {
+        address: "127.0.0.1".to_string(),
+        accent: "rgb(0, 0, 0)".to_string(),
+        slug: "Home".to_string(),
+        email: "root".to_string(),
+    };
+
+    let errors = server.validate().unwrap_err();
+    let params: Vec<_> = errors
+        .validation_errors()
+        .map(|(path, error)| (path.to_string(), error.params))
+        .collect();
+    assert_eq!(
+        params,
+        [
+            ("accent".to_string(), [("format", "hex".to_string())].into()),
+            (
+                "address".to_string(),
+                [("version", "v6".to_string())].into()
+            ),
+            ("email".to_string(), [("values", "@,.".to_string())].into()),
+            (
+                "slug".to_string(),
+                [("pattern", "^[a-z]+$".to_string())].into()
+            ),
+        ]
+    );
+}
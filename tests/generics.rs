@@ -0,0 +1,120 @@
+use std::fmt::Debug;
+
+use validrs::validate::{Validate, ValidateWithContext};
+use validrs_derive::Valid;
+
+#[derive(Clone, Valid)]
+struct Item {
+    #[valid(required)]
+    name: String,
+}
+
+#[derive(Valid)]
+struct Page<T> {
+    #[valid(len(min = 0, max = 3))]
+    items: Vec<T>,
+}
+
+#[derive(Valid)]
+struct Nested<T, const N: usize = 2>
+where
+    T: Debug,
+{
+    #[valid(nested)]
+    items: Vec<T>,
+
+    #[valid(rng(max = 5))]
+    size: usize,
+}
+
+#[derive(Valid)]
+struct Request<'a> {
+    #[valid(len(min = 1, max = 16))]
+    name: &'a str,
+
+    #[valid(email)]
+    email: &'a str,
+}
+
+#[derive(Valid)]
+#[valid(bound = "T: Validate + Clone")]
+struct Explicit<T> {
+    #[valid(nested)]
+    inner: Option<T>,
+}
+
+#[derive(Valid)]
+struct Tree {
+    #[valid(required)]
+    label: String,
+
+    #[valid(nested)]
+    children: Vec<Tree>,
+}
+
+#[derive(Debug, Valid)]
+struct Named {
+    #[valid(len(min = 1, max = 8))]
+    name: String,
+}
+
+#[test]
+fn test_generic_struct() {
+    let page = Page {
+        items: vec![1, 2, 3, 4],
+    };
+    assert!(page.validate().unwrap_err().field("items").is_some());
+}
+
+#[test]
+fn test_inferred_bounds() {
+    let page: Nested<Named> = Nested {
+        items: vec![Named {
+            name: String::new(),
+        }],
+        size: 1,
+    };
+
+    let errors = page.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["items[0].name"]);
+    assert!(page.validate_with_context(&()).is_err());
+}
+
+#[test]
+fn test_lifetimes() {
+    let name = String::from("John");
+    let request = Request {
+        name: &name,
+        email: "invalid",
+    };
+
+    let errors = request.validate().unwrap_err();
+    assert!(errors.field("email").is_some());
+    assert!(errors.field("name").is_none());
+}
+
+#[test]
+fn test_explicit_bound() {
+    let explicit = Explicit {
+        inner: Some(Item {
+            name: String::new(),
+        }),
+    };
+    assert!(explicit.validate().is_err());
+}
+
+#[test]
+fn test_recursive_struct() {
+    let tree = Tree {
+        label: "root".to_string(),
+        children: vec![Tree {
+            label: String::new(),
+            children: Vec::new(),
+        }],
+    };
+
+    let errors = tree.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["children[0].label"]);
+}
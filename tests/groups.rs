@@ -0,0 +1,77 @@
+use validrs::validate::{Group, Validate};
+use validrs_derive::Valid;
+
+fn has_id(user: &User) -> validrs::error::Result<()> {
+    match user.id {
+        Some(_) => Ok(()),
+        None => Err(validrs::error::Error::Required),
+    }
+}
+
+#[derive(Valid)]
+struct Address {
+    #[valid(len(min = 4, max = 11), groups = [create, update])]
+    zip: String,
+}
+
+#[derive(Valid)]
+#[valid(schema(function = has_id, fields = ["id"]), groups = [update])]
+struct User {
+    id: Option<u64>,
+
+    #[valid(required, groups = [create])]
+    #[valid(len(min = 1, max = 16), groups = [default, create, update])]
+    name: String,
+
+    #[valid(email)]
+    #[valid(required, groups = [admin])]
+    email: String,
+
+    #[valid(nested)]
+    address: Address,
+}
+
+fn user(id: Option<u64>, name: &str, email: &str, zip: &str) -> User {
+    User {
+        id,
+        name: name.to_string(),
+        email: email.to_string(),
+        address: Address {
+            zip: zip.to_string(),
+        },
+    }
+}
+
+#[test]
+fn test_default_group() {
+    let user = user(None, "John", "invalid", "1");
+
+    let errors = user.validate().unwrap_err();
+    assert!(errors.field("email").is_some());
+    assert!(errors.field("id").is_none());
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_create_group() {
+    let user = user(None, "", "invalid", "1");
+
+    let errors = user.validate_group(Group::Create).unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+
+    assert_eq!(paths, ["address.zip", "name"]);
+    assert_eq!(errors.field("name").unwrap().len(), 2);
+}
+
+#[test]
+fn test_update_and_custom_groups() {
+    let user = user(None, "John", "", "10115");
+
+    let errors = user.validate_group(Group::Update).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors.field("id").is_some());
+
+    let errors = user.validate_group(Group::Custom("admin")).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors.field("email").is_some());
+}
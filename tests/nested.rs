@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+
+use validrs::error::Error;
+use validrs::validate::{Group, Validate};
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Address {
+    #[valid(len(min = 4, max = 11))]
+    zip: String,
+}
+
+#[derive(Valid)]
+struct Order {
+    #[valid(required)]
+    id: String,
+
+    #[valid(nested)]
+    billing: Address,
+
+    #[valid(nested)]
+    shipping: Option<Box<Address>>,
+
+    #[valid(nested)]
+    addresses: Vec<Address>,
+
+    #[valid(nested)]
+    labels: BTreeMap<String, Address>,
+}
+
+fn address(zip: &str) -> Address {
+    Address {
+        zip: zip.to_string(),
+    }
+}
+
+#[test]
+fn test_nested_errors_are_prefixed() {
+    let order = Order {
+        id: String::new(),
+        billing: address("1"),
+        shipping: Some(Box::new(address("2"))),
+        addresses: vec![address("10115"), address("3"), address("75001")],
+        labels: BTreeMap::from([("home".to_string(), address("4"))]),
+    };
+
+    let errors = order.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+
+    assert_eq!(
+        paths,
+        [
+            "addresses[1].zip",
+            "billing.zip",
+            "id",
+            r#"labels["home"].zip"#,
+            "shipping.zip",
+        ]
+    );
+}
+
+#[test]
+fn test_nested_none_is_skipped() {
+    let order = Order {
+        id: "42".to_string(),
+        billing: address("10115"),
+        shipping: None,
+        addresses: vec![],
+        labels: BTreeMap::new(),
+    };
+
+    assert!(order.validate().is_ok());
+}
+
+#[derive(Valid)]
+struct Checkout {
+    #[valid(nested, none = reject)]
+    billing: Option<Address>,
+
+    #[valid(nested, len(max = 1), none = reject)]
+    shipping: Option<Vec<Address>>,
+}
+
+#[test]
+fn test_nested_none_reject() {
+    let checkout = Checkout {
+        billing: None,
+        shipping: None,
+    };
+
+    for group in [Group::Default, Group::Create] {
+        let errors = checkout.validate_group(group).unwrap_err();
+        assert!(matches!(
+            errors.field("billing").unwrap()[..],
+            [Error::Required]
+        ));
+        assert!(matches!(
+            errors.field("shipping").unwrap()[..],
+            [Error::Required]
+        ));
+    }
+
+    let checkout = Checkout {
+        billing: Some(address("10115")),
+        shipping: Some(vec![address("1")]),
+    };
+    let errors = checkout.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["shipping[0].zip"]);
+}
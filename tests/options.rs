@@ -0,0 +1,255 @@
+use std::sync::LazyLock;
+
+use validrs::error::{Error, Result};
+use validrs::patch::Patch;
+use validrs::rules::regex::Regex;
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+static DIGITS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d+$").unwrap());
+
+fn never<T>(_: &T) -> Result<()> {
+    Err(Error::Custom("never valid".to_string()))
+}
+
+/// One field per rule trait, each `None` by default.
+#[derive(Valid, Default)]
+struct Optional {
+    #[valid(len(min = 1))]
+    len: Option<String>,
+    #[valid(rng(min = 1))]
+    rng: Option<i32>,
+    #[valid(contains(["@"]))]
+    contains: Option<String>,
+    #[valid(ip)]
+    ip: Option<String>,
+    #[valid(color)]
+    color: Option<String>,
+    #[valid(regex(path = DIGITS))]
+    regex: Option<String>,
+    #[valid(regex(pattern = r"^\d+$"))]
+    pattern: Option<String>,
+    #[valid(email)]
+    email: Option<String>,
+    #[valid(phone)]
+    phone: Option<String>,
+    #[valid(alphanumeric)]
+    alphanumeric: Option<String>,
+    #[valid(alphabetic)]
+    alphabetic: Option<String>,
+    #[valid(ascii)]
+    ascii: Option<String>,
+    #[valid(lowercase)]
+    lowercase: Option<String>,
+    #[valid(uppercase)]
+    uppercase: Option<String>,
+    #[valid(positive)]
+    positive: Option<i32>,
+    #[valid(negative)]
+    negative: Option<i32>,
+    #[valid(must_match = "len")]
+    must_match: Option<String>,
+    #[valid(gt_field = "rng")]
+    gt_field: Option<i32>,
+    #[valid(lt_field = "rng")]
+    lt_field: Option<i32>,
+    #[valid(custom(function = never))]
+    custom: Option<String>,
+}
+
+/// The same rules on explicit `null`s of a PATCH body.
+#[derive(Valid)]
+struct Nulls {
+    #[valid(len(min = 1))]
+    len: Patch<String>,
+    #[valid(rng(min = 1))]
+    rng: Patch<i32>,
+    #[valid(contains(["@"]))]
+    contains: Patch<String>,
+    #[valid(ip)]
+    ip: Patch<String>,
+    #[valid(color)]
+    color: Patch<String>,
+    #[valid(regex(path = DIGITS))]
+    regex: Patch<String>,
+    #[valid(regex(pattern = r"^\d+$"))]
+    pattern: Patch<String>,
+    #[valid(email)]
+    email: Patch<String>,
+    #[valid(phone)]
+    phone: Patch<String>,
+    #[valid(alphanumeric)]
+    alphanumeric: Patch<String>,
+    #[valid(alphabetic)]
+    alphabetic: Patch<String>,
+    #[valid(ascii)]
+    ascii: Patch<String>,
+    #[valid(lowercase)]
+    lowercase: Patch<String>,
+    #[valid(uppercase)]
+    uppercase: Patch<String>,
+    #[valid(positive)]
+    positive: Patch<i32>,
+    #[valid(negative)]
+    negative: Patch<i32>,
+    #[valid(must_match = "len")]
+    must_match: Patch<String>,
+    #[valid(gt_field = "rng")]
+    gt_field: Patch<i32>,
+    #[valid(lt_field = "rng")]
+    lt_field: Patch<i32>,
+    #[valid(custom(function = never))]
+    custom: Patch<String>,
+}
+
+#[test]
+fn test_every_rule_passes_on_none() {
+    assert!(Optional::default().validate().is_ok());
+}
+
+#[test]
+fn test_every_rule_passes_on_null() {
+    let nulls = Nulls {
+        len: Patch::Null,
+        rng: Patch::Null,
+        contains: Patch::Null,
+        ip: Patch::Null,
+        color: Patch::Null,
+        regex: Patch::Null,
+        email: Patch::Null,
+        phone: Patch::Null,
+        alphanumeric: Patch::Null,
+        alphabetic: Patch::Null,
+        ascii: Patch::Null,
+        lowercase: Patch::Null,
+        uppercase: Patch::Null,
+        positive: Patch::Null,
+        negative: Patch::Null,
+        pattern: Patch::Null,
+        must_match: Patch::Null,
+        gt_field: Patch::Null,
+        lt_field: Patch::Null,
+        custom: Patch::Null,
+    };
+    assert!(nulls.validate().is_ok());
+}
+
+#[test]
+fn test_every_rule_checks_some() {
+    let invalid = Optional {
+        len: Some(String::new()),
+        rng: Some(0),
+        contains: Some("example.com".to_string()),
+        ip: Some("localhost".to_string()),
+        color: Some("blue-ish".to_string()),
+        regex: Some("12a".to_string()),
+        email: Some("@".to_string()),
+        phone: Some("call me".to_string()),
+        alphanumeric: Some("a-b".to_string()),
+        alphabetic: Some("a1".to_string()),
+        ascii: Some("\u{e9}".to_string()),
+        lowercase: Some("Abc".to_string()),
+        uppercase: Some("aBC".to_string()),
+        positive: Some(-1),
+        negative: Some(1),
+        pattern: Some("12a".to_string()),
+        must_match: Some("x".to_string()),
+        gt_field: Some(0),
+        lt_field: Some(0),
+        custom: Some(String::new()),
+    };
+
+    let errors = invalid.validate().unwrap_err();
+    assert_eq!(errors.fields().count(), 20);
+}
+
+#[test]
+fn test_comparisons_pass_on_either_side_none() {
+    let missing_other = Optional {
+        must_match: Some("x".to_string()),
+        gt_field: Some(0),
+        lt_field: Some(0),
+        ..Default::default()
+    };
+    assert!(missing_other.validate().is_ok());
+
+    let missing_self = Optional {
+        len: Some("x".to_string()),
+        rng: Some(1),
+        ..Default::default()
+    };
+    assert!(missing_self.validate().is_ok());
+}
+
+#[derive(Valid)]
+struct Signup {
+    #[valid(required)]
+    #[valid(email)]
+    email: Option<String>,
+
+    #[valid(phone, none = reject, groups = [create])]
+    #[valid(len(max = 16))]
+    phone: Option<String>,
+
+    #[valid(rng(min = 18), none = reject)]
+    age: Patch<u8>,
+
+    #[valid(must_match = "email", none = reject)]
+    confirm_email: Option<String>,
+}
+
+#[test]
+fn test_required_rejects_none() {
+    let signup = Signup {
+        email: None,
+        phone: Some("+14155552671".to_string()),
+        age: Patch::Absent,
+        confirm_email: None,
+    };
+    let errors = signup.validate().unwrap_err();
+    assert!(matches!(
+        errors.field("email").unwrap()[..],
+        [Error::Required]
+    ));
+}
+
+#[test]
+fn test_none_reject_is_per_rule() {
+    let signup = Signup {
+        email: Some("a@example.com".to_string()),
+        phone: None,
+        age: Patch::Null,
+        confirm_email: Some("a@example.com".to_string()),
+    };
+
+    let errors = signup.validate().unwrap_err();
+    assert!(errors.field("phone").is_none());
+    assert!(matches!(
+        errors.field("age").unwrap()[..],
+        [Error::Required]
+    ));
+
+    let errors = signup
+        .validate_group(validrs::validate::Group::Create)
+        .unwrap_err();
+    assert!(matches!(
+        errors.field("phone").unwrap()[..],
+        [Error::Required]
+    ));
+}
+
+#[test]
+fn test_none_reject_compares_missing_values() {
+    let signup = Signup {
+        email: None,
+        phone: None,
+        age: Patch::Value(18),
+        confirm_email: Some("a@example.com".to_string()),
+    };
+
+    let errors = signup.validate().unwrap_err();
+    assert!(matches!(
+        errors.field("confirm_email").unwrap()[..],
+        [Error::MustMatch { .. }]
+    ));
+}
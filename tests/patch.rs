@@ -0,0 +1,163 @@
+use validrs::patch::Patch;
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+fn not_admin(name: &Patch<String>) -> validrs::error::Result<()> {
+    match name.value().map(String::as_str) {
+        Some("admin") => Err(validrs::error::Error::Custom("reserved".to_string())),
+        _ => Ok(()),
+    }
+}
+
+fn unreachable(_: &Patch<String>) -> validrs::error::Result<()> {
+    panic!("rules of unlisted fields must not run")
+}
+
+#[derive(Valid)]
+struct Address {
+    #[valid(len(min = 4, max = 11))]
+    zip: String,
+}
+
+#[derive(Valid, Default)]
+struct UpdateUser {
+    #[valid(len(min = 2, max = 16), custom(function = not_admin))]
+    name: Patch<String>,
+
+    #[valid(email)]
+    email: Patch<String>,
+
+    #[valid(required)]
+    nickname: Patch<String>,
+
+    #[valid(custom(function = unreachable), when = has_bio)]
+    bio: Patch<String>,
+
+    #[valid(nested)]
+    address: Patch<Address>,
+}
+
+fn has_bio(user: &UpdateUser) -> bool {
+    user.bio.is_value()
+}
+
+#[test]
+fn test_absent_fields_are_skipped() {
+    assert!(UpdateUser::default().validate().is_ok());
+}
+
+#[test]
+fn test_values_are_validated() {
+    let user = UpdateUser {
+        name: Patch::Value("admin".to_string()),
+        email: Patch::Value("invalid".to_string()),
+        address: Patch::Value(Address {
+            zip: "1".to_string(),
+        }),
+        ..Default::default()
+    };
+
+    let errors = user.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["address.zip", "email", "name"]);
+}
+
+#[test]
+fn test_null_fields() {
+    let user = UpdateUser {
+        email: Patch::Null,
+        nickname: Patch::Null,
+        address: Patch::Null,
+        ..Default::default()
+    };
+
+    let errors = user.validate().unwrap_err();
+    assert!(errors.field("nickname").is_some());
+    assert!(errors.field("email").is_none());
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_validate_fields() {
+    let user = UpdateUser {
+        name: Patch::Value("a".to_string()),
+        email: Patch::Value("invalid".to_string()),
+        ..Default::default()
+    };
+
+    assert!(user.validate_fields(&["nickname"]).is_ok());
+
+    let user = UpdateUser {
+        bio: Patch::Value("hello".to_string()),
+        ..user
+    };
+
+    let errors = user.validate_fields(&["email", "nickname"]).unwrap_err();
+    assert!(errors.field("email").is_some());
+    assert!(errors.field("name").is_none());
+}
+
+#[test]
+fn test_patch_from_option() {
+    assert_eq!(Patch::from(Some(1)), Patch::Value(1));
+    assert_eq!(Patch::<i32>::from(None), Patch::Null);
+    assert_eq!(Patch::Value(1).into_option(), Some(Some(1)));
+    assert_eq!(Patch::<i32>::Absent.into_option(), None);
+}
+
+mod local {
+    /// An unrelated type that happens to be called `Patch`.
+    pub struct Patch(pub String);
+}
+
+fn not_empty(contact: &local::Patch) -> validrs::error::Result<()> {
+    match contact.0.is_empty() {
+        true => Err(validrs::error::Error::Required),
+        false => Ok(()),
+    }
+}
+
+type Update<T> = Patch<T>;
+
+#[derive(Valid)]
+struct Renamed {
+    #[valid(email)]
+    email: Update<String>,
+
+    #[valid(custom(function = not_empty))]
+    contact: local::Patch,
+}
+
+#[test]
+fn test_patch_is_detected_by_type() {
+    let renamed = Renamed {
+        email: Patch::Absent,
+        contact: local::Patch(String::new()),
+    };
+
+    let errors = renamed.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["contact"]);
+
+    let renamed = Renamed {
+        email: Patch::Value("invalid".to_string()),
+        contact: local::Patch("+14155552671".to_string()),
+    };
+    assert!(renamed.validate_fields(&["email"]).is_err());
+}
+
+#[derive(Valid)]
+struct UpdateKind {
+    #[valid(len(min = 2))]
+    r#type: Patch<String>,
+}
+
+#[test]
+fn test_validate_fields_with_raw_identifier() {
+    let update = UpdateKind {
+        r#type: Patch::Value("a".to_string()),
+    };
+
+    let errors = update.validate_fields(&["type"]).unwrap_err();
+    assert!(errors.field("type").is_some());
+}
@@ -0,0 +1,66 @@
+use validrs::error::Error;
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+#[valid(one_of_required(email, phone))]
+#[valid(mutually_exclusive(card, iban, msg = "Pay either by card or by bank transfer"))]
+#[valid(exactly_one(standard, express, pickup))]
+struct Checkout {
+    email: Option<String>,
+    phone: Option<String>,
+    card: String,
+    iban: String,
+    standard: Option<bool>,
+    express: Option<bool>,
+    pickup: Option<bool>,
+}
+
+#[test]
+fn test_groups_pass() {
+    let checkout = Checkout {
+        email: None,
+        phone: Some("+442072222222".into()),
+        card: "4242".into(),
+        iban: String::new(),
+        standard: None,
+        express: Some(true),
+        pickup: None,
+    };
+
+    assert!(checkout.validate().is_ok());
+}
+
+#[test]
+fn test_group_errors_are_reported_on_every_field() {
+    let checkout = Checkout {
+        email: None,
+        phone: None,
+        card: "4242".into(),
+        iban: "DE89".into(),
+        standard: Some(true),
+        express: Some(true),
+        pickup: None,
+    };
+
+    let errors = checkout.validate().unwrap_err();
+
+    for field in ["email", "phone"] {
+        assert!(matches!(
+            &errors.field(field).unwrap()[0],
+            Error::OneOfRequired { fields } if fields == &["email", "phone"]
+        ));
+    }
+    for field in ["card", "iban"] {
+        assert_eq!(
+            errors.field(field).unwrap()[0].to_string(),
+            "Pay either by card or by bank transfer"
+        );
+    }
+    for field in ["standard", "express", "pickup"] {
+        assert_eq!(
+            errors.field(field).unwrap()[0].to_string(),
+            "Exactly one of standard, express, pickup is required"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::fmt;
+use std::time::Duration;
+
+use validrs::error::Error;
+use validrs::rules::bound::BoundKind;
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+const MAX_AGE: u8 = 130;
+
+#[derive(Valid)]
+struct Profile {
+    #[valid(len(1..=16, msg = "Between {{min}} and {{max}} characters"))]
+    handle: String,
+
+    #[valid(len(..4))]
+    initials: String,
+
+    #[valid(rng(18..=MAX_AGE))]
+    age: u8,
+
+    #[valid(rng(18..))]
+    since: u8,
+
+    #[valid(rng(..0.5))]
+    ratio: f64,
+}
+
+fn profile() -> Profile {
+    Profile {
+        handle: "ferris".to_string(),
+        initials: "FR".to_string(),
+        age: 18,
+        since: 30,
+        ratio: 0.25,
+    }
+}
+
+#[test]
+fn test_inclusive_ranges() {
+    let profile = Profile {
+        handle: "a".repeat(16),
+        age: MAX_AGE,
+        ..profile()
+    };
+    assert!(profile.validate().is_ok());
+
+    let profile = Profile {
+        handle: "a".repeat(17),
+        age: MAX_AGE + 1,
+        ..profile
+    };
+    let errors = profile.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["age", "handle"]);
+}
+
+#[test]
+fn test_exclusive_end() {
+    let profile = Profile {
+        initials: "ABC".to_string(),
+        ratio: 0.49,
+        ..profile()
+    };
+    assert!(profile.validate().is_ok());
+
+    let profile = Profile {
+        initials: "ABCD".to_string(),
+        ratio: 0.5,
+        ..profile
+    };
+    let errors = profile.validate().unwrap_err();
+    assert!(errors.field("initials").is_some());
+    assert!(errors.field("ratio").is_some());
+}
+
+#[test]
+fn test_open_start() {
+    let profile = Profile {
+        since: 17,
+        ..profile()
+    };
+    assert!(profile.validate().unwrap_err().field("since").is_some());
+}
+
+#[test]
+fn test_range_template() {
+    let profile = Profile {
+        handle: String::new(),
+        ..profile()
+    };
+    assert_eq!(
+        profile.validate().unwrap_err().field("handle").unwrap()[0].to_string(),
+        "Between 1 and 16 characters"
+    );
+}
+
+const MAX_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Valid)]
+struct Reading {
+    #[valid(rng(-40.0..=85.0))]
+    celsius: f32,
+
+    #[valid(rng(min = -720, max = 840))]
+    utc_offset: i16,
+
+    #[valid(rng(..=MAX_TIMEOUT))]
+    timeout: Duration,
+}
+
+fn reading() -> Reading {
+    Reading {
+        celsius: -12.5,
+        utc_offset: -300,
+        timeout: Duration::from_secs(5),
+    }
+}
+
+#[test]
+fn test_signed_float_and_duration_ranges() {
+    assert!(reading().validate().is_ok());
+
+    let reading = Reading {
+        celsius: -40.5,
+        utc_offset: -721,
+        timeout: Duration::from_secs(31),
+    };
+    let errors = reading.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["celsius", "timeout", "utc_offset"]);
+}
+
+#[test]
+fn test_nan_is_out_of_range() {
+    let reading = Reading {
+        celsius: f32::NAN,
+        ..reading()
+    };
+    assert!(reading.validate().unwrap_err().field("celsius").is_some());
+}
+
+#[derive(Valid)]
+struct Card {
+    #[valid(len(equal = 4, msg = "Exactly {{equal}} digits"))]
+    pin: String,
+
+    #[valid(len(min = 1, exclusive_max = 5))]
+    tags: Vec<String>,
+
+    #[valid(rng(exclusive_min = 0.0, max = 100.0))]
+    amount: f64,
+}
+
+fn card() -> Card {
+    Card {
+        pin: "1234".to_string(),
+        tags: vec!["a".to_string()],
+        amount: 100.0,
+    }
+}
+
+#[test]
+fn test_equal_and_exclusive_bounds() {
+    assert!(card().validate().is_ok());
+
+    let card = Card {
+        tags: vec![String::new(); 5],
+        amount: 0.0,
+        ..card()
+    };
+    let errors = card.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["amount", "tags"]);
+}
+
+#[test]
+fn test_failed_bound_kind() {
+    let card = Card {
+        pin: "123".to_string(),
+        amount: 0.0,
+        ..card()
+    };
+    let errors = card.validate().unwrap_err();
+    assert_eq!(
+        errors.field("pin").unwrap()[0].to_string(),
+        "Exactly 4 digits"
+    );
+    assert!(matches!(
+        errors.field("amount").unwrap()[0],
+        Error::OutOfRange {
+            kind: BoundKind::ExclusiveMin,
+            ..
+        }
+    ));
+}
+
+#[derive(Valid)]
+struct Post {
+    #[valid(len(max = 8, unit = bytes))]
+    slug: String,
+
+    #[valid(len(max = 4, unit = utf16))]
+    title: String,
+
+    #[valid(len(equal = 1, unit = graphemes))]
+    reaction: String,
+
+    #[valid(len(max = 2))]
+    flag: String,
+}
+
+#[test]
+fn test_length_units() {
+    let post = Post {
+        slug: "caf\u{e9}-12".to_string(),
+        title: "\u{1F600}ab".to_string(),
+        reaction: "\u{1F44D}\u{1F3FD}".to_string(),
+        flag: "\u{1F1EB}\u{1F1F7}".to_string(),
+    };
+    assert!(post.validate().is_ok());
+
+    let post = Post {
+        slug: "caf\u{e9}-123".to_string(),
+        title: "\u{1F600}\u{1F600}a".to_string(),
+        reaction: "\u{1F44D}\u{1F44D}".to_string(),
+        flag: "\u{1F1EB}\u{1F1F7}!".to_string(),
+    };
+    let errors = post.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["flag", "reaction", "slug", "title"]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Money(i64);
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} cents", self.0)
+    }
+}
+
+validrs::impl_validate_range!(Money);
+
+#[derive(Valid)]
+struct Invoice {
+    #[valid(rng(min = Money(0)))]
+    total: Money,
+
+    #[valid(rng(max = Money(100)))]
+    discount: Option<Money>,
+}
+
+#[test]
+fn test_user_type_range() {
+    let invoice = Invoice {
+        total: Money(0),
+        discount: Some(Money(100)),
+    };
+    assert!(invoice.validate().is_ok());
+
+    let invoice = Invoice {
+        total: Money(-1),
+        discount: Some(Money(101)),
+    };
+    let errors = invoice.validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["discount", "total"]);
+    assert!(matches!(
+        &errors.field("total").unwrap()[0],
+        Error::OutOfRange { limit, .. } if limit == "0 cents"
+    ));
+}
@@ -0,0 +1,42 @@
+use std::sync::LazyLock;
+
+use validrs::error::Error;
+use validrs::rules::regex::Regex;
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+static SKU_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Z]{3}-\d{4}$").unwrap());
+
+#[derive(Valid)]
+struct Product {
+    #[valid(regex(pattern = r"^\d{3}-\d{2}-\d{4}$"))]
+    code: String,
+
+    #[valid(regex(path = SKU_REGEX, msg = "Unknown SKU format"))]
+    sku: String,
+}
+
+#[test]
+fn test_regex_pattern_and_path() {
+    let product = Product {
+        code: "123-45-6789".to_string(),
+        sku: "ABC-1234".to_string(),
+    };
+    assert!(product.validate().is_ok());
+
+    let product = Product {
+        code: "invalid".to_string(),
+        sku: "abc".to_string(),
+    };
+    let errors = product.validate().unwrap_err();
+
+    assert!(matches!(
+        errors.field("code").unwrap()[0],
+        Error::NoMatch { .. }
+    ));
+    assert_eq!(
+        errors.field("sku").unwrap()[0].to_string(),
+        "Unknown SKU format"
+    );
+    assert_eq!(errors.field("sku").unwrap()[0].code(), "regex");
+}
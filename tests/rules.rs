@@ -0,0 +1,96 @@
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Profile {
+    #[valid(email)]
+    email: String,
+
+    #[valid(ip(v4, msg = "IPv4 address expected"))]
+    ip: String,
+
+    #[valid(phone)]
+    phone: String,
+
+    #[valid(color(hex))]
+    color: String,
+
+    #[valid(alphanumeric, ascii)]
+    login: String,
+
+    #[valid(alphabetic)]
+    first_name: String,
+
+    #[valid(lowercase)]
+    slug: String,
+
+    #[valid(uppercase)]
+    country: String,
+
+    #[valid(positive(msg = "Balance must be positive"))]
+    balance: i64,
+
+    #[valid(negative)]
+    offset: i32,
+}
+
+fn valid_profile() -> Profile {
+    Profile {
+        email: "test@gmail.com".to_string(),
+        ip: "1.1.1.1".to_string(),
+        phone: "+442072222222".to_string(),
+        color: "#a1b2c3".to_string(),
+        login: "john42".to_string(),
+        first_name: "John".to_string(),
+        slug: "john".to_string(),
+        country: "GB".to_string(),
+        balance: 10,
+        offset: -1,
+    }
+}
+
+#[test]
+fn test_valid_profile() {
+    assert!(valid_profile().validate().is_ok());
+}
+
+#[test]
+fn test_every_rule_reports_its_field() {
+    let profile = Profile {
+        email: "not an email".to_string(),
+        ip: "::1".to_string(),
+        phone: "12345".to_string(),
+        color: "rgb(0, 0, 0)".to_string(),
+        login: "jöhn-42".to_string(),
+        first_name: "John2".to_string(),
+        slug: "John".to_string(),
+        country: "gb".to_string(),
+        balance: 0,
+        offset: 1,
+    };
+
+    let errors = profile.validate().unwrap_err();
+
+    for field in [
+        "email",
+        "ip",
+        "phone",
+        "color",
+        "first_name",
+        "slug",
+        "country",
+        "balance",
+        "offset",
+    ] {
+        assert_eq!(errors.field(field).map(<[_]>::len), Some(1), "{field}");
+    }
+    assert_eq!(errors.field("login").map(<[_]>::len), Some(2));
+    assert_eq!(
+        errors.field("ip").unwrap()[0].to_string(),
+        "IPv4 address expected"
+    );
+    assert_eq!(
+        errors.field("balance").unwrap()[0].to_string(),
+        "Balance must be positive"
+    );
+}
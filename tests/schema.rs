@@ -0,0 +1,99 @@
+use validrs::error::{Error, Result};
+use validrs::path::Path;
+use validrs::validate::{Validate, ValidateWithContext};
+use validrs_derive::Valid;
+
+fn check_dates(booking: &Booking) -> Result<()> {
+    match booking.start < booking.end {
+        true => Ok(()),
+        false => Err(Error::Custom("End must be after start".into())),
+    }
+}
+
+fn check_contact(booking: &Booking) -> Result<()> {
+    match booking.email.is_some() || booking.phone.is_some() {
+        true => Ok(()),
+        false => Err(Error::Required),
+    }
+}
+
+#[derive(Valid)]
+#[valid(schema(function = check_dates, fields = ["start", "end"]))]
+#[valid(schema(function = check_contact, msg = "Email or phone is required"))]
+struct Booking {
+    #[valid(positive)]
+    start: u32,
+    end: u32,
+    email: Option<String>,
+    phone: Option<String>,
+}
+
+#[test]
+fn test_schema_errors_on_fields_and_root() {
+    let booking = Booking {
+        start: 0,
+        end: 0,
+        email: None,
+        phone: None,
+    };
+
+    let errors = booking.validate().unwrap_err();
+
+    assert_eq!(errors.field("start").unwrap().len(), 2);
+    assert_eq!(
+        errors.field("end").unwrap()[0].to_string(),
+        "End must be after start"
+    );
+    assert_eq!(
+        errors.get(&Path::root()).unwrap()[0].to_string(),
+        "Email or phone is required"
+    );
+}
+
+#[test]
+fn test_schema_passes() {
+    let booking = Booking {
+        start: 1,
+        end: 2,
+        email: None,
+        phone: Some("+442072222222".into()),
+    };
+
+    assert!(booking.validate().is_ok());
+}
+
+#[test]
+fn test_validate_fields_skips_struct_rules() {
+    let booking = Booking {
+        start: 0,
+        end: 0,
+        email: None,
+        phone: None,
+    };
+
+    assert!(booking.validate_fields(&["end", "email"]).is_ok());
+
+    let errors = booking.validate_fields(&["start"]).unwrap_err();
+    assert_eq!(errors.to_string(), "start: Number must be positive");
+}
+
+#[test]
+fn test_validate_fields_through_pointers() {
+    let booking = Booking {
+        start: 0,
+        end: 0,
+        email: None,
+        phone: None,
+    };
+
+    assert!(Box::new(&booking).validate_fields(&["end"]).is_ok());
+    assert!(Some(&booking).validate_fields(&["end"]).is_ok());
+    assert!(
+        std::rc::Rc::new(&booking)
+            .validate_fields_with_context(&(), &["end"])
+            .is_ok()
+    );
+
+    let errors = Box::new(booking).validate_fields(&["start"]).unwrap_err();
+    assert_eq!(errors.len(), 1);
+}
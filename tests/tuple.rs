@@ -0,0 +1,80 @@
+use validrs::validate::Validate;
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Email(#[valid(email)] String);
+
+#[derive(Valid)]
+struct Range(
+    #[valid(positive)] i32,
+    #[valid(positive, gt_field = "0")] i32,
+);
+
+#[derive(Valid)]
+struct User {
+    #[valid(nested)]
+    email: Email,
+}
+
+#[derive(Valid)]
+enum Contact {
+    Email(#[valid(nested)] Email),
+    Phone(#[valid(phone)] String),
+}
+
+#[test]
+fn test_newtype() {
+    assert!(Email("john@example.com".to_string()).validate().is_ok());
+
+    let errors = Email("invalid".to_string()).validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["[0]"]);
+}
+
+#[test]
+fn test_tuple_struct() {
+    assert!(Range(1, 2).validate().is_ok());
+
+    let errors = Range(-1, -2).validate().unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["[0]", "[1]"]);
+    assert_eq!(errors.len(), 3);
+}
+
+#[test]
+fn test_tuple_struct_fields() {
+    let errors = Range(1, -2).validate_fields(&["1"]).unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["[1]"]);
+
+    assert!(Range(1, -2).validate_fields(&["0"]).is_ok());
+}
+
+#[test]
+fn test_nested_newtype() {
+    let user = User {
+        email: Email("invalid".to_string()),
+    };
+
+    let errors = user.validate().unwrap_err();
+    let paths: Vec<_> = errors
+        .fields()
+        .map(|(path, _)| path.to_json_pointer())
+        .collect();
+    assert_eq!(paths, ["/email/0"]);
+}
+
+#[test]
+fn test_tuple_variant() {
+    assert!(
+        Contact::Phone("+12125551212".to_string())
+            .validate()
+            .is_ok()
+    );
+
+    let errors = Contact::Email(Email("invalid".to_string()))
+        .validate()
+        .unwrap_err();
+    let paths: Vec<_> = errors.fields().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(paths, ["Email[0][0]"]);
+}
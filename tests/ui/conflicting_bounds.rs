@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Card {
+    #[valid(len(equal = 4, max = 8))]
+    pin: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct User {
+    #[valid(contains(["@", 1]))]
+    email: String,
+}
+
+fn main() {}
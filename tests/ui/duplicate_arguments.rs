@@ -0,0 +1,45 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Pattern {
+    #[valid(regex(pattern = "^a", pattern = "^b"))]
+    code: String,
+}
+
+#[derive(Valid)]
+struct Message {
+    #[valid(len(max = 8, msg = "Too long", msg = "Way too long"))]
+    name: String,
+}
+
+#[derive(Valid)]
+struct Source {
+    #[valid(regex(pattern = "^a", path = CODE))]
+    code: String,
+}
+
+#[derive(Valid)]
+struct Unit {
+    #[valid(len(max = 8, unit = bytes, unit = chars))]
+    slug: String,
+}
+
+#[derive(Valid)]
+struct Address {
+    #[valid(ip(v4, v6))]
+    ip: String,
+}
+
+#[derive(Valid)]
+struct Theme {
+    #[valid(color(hex, rgb))]
+    accent: String,
+}
+
+#[derive(Valid)]
+struct Check {
+    #[valid(custom(function = check, function = check))]
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Profile {
+    #[valid(len(4..4))]
+    initials: String,
+}
+
+fn main() {}
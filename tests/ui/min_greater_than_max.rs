@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct User {
+    #[valid(len(min = 16, max = 1))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Reading {
+    #[valid(rng(min = -10, max = 0.5))]
+    celsius: i32,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Empty {
+    #[valid(len())]
+    name: String,
+}
+
+#[derive(Valid)]
+struct Message {
+    #[valid(len(msg = "Too long"))]
+    name: String,
+}
+
+#[derive(Valid)]
+struct Unit {
+    #[valid(len(unit = bytes))]
+    slug: String,
+}
+
+#[derive(Valid)]
+struct Unbounded {
+    #[valid(rng(..))]
+    age: u8,
+}
+
+fn main() {}
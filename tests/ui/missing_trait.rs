@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct User {
+    #[valid(email)]
+    age: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+#[valid(one_of_required(email, phone), none = reject)]
+struct Contact {
+    email: Option<String>,
+    phone: Option<String>,
+}
+
+fn main() {}
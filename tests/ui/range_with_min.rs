@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Profile {
+    #[valid(rng(18.., max = 130))]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct User {
+    #[valid(len(mni = 1, max = 16))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Registration {
+    password: String,
+
+    #[valid(must_match(other = "pasword"))]
+    confirmation: String,
+}
+
+fn main() {}
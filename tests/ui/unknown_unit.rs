@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct Post {
+    #[valid(len(max = 255, unit = byte))]
+    slug: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validrs_derive::Valid;
+
+#[derive(Valid)]
+struct User {
+    #[valid(emial)]
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,83 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, quote};
+
+/// Generics of the generated impls: the ones of the input plus the inferred or
+/// user-provided bounds.
+pub struct Bounds<'a> {
+    generics: &'a syn::Generics,
+}
+
+impl<'a> Bounds<'a> {
+    pub fn new(generics: &'a syn::Generics) -> Self {
+        Self { generics }
+    }
+
+    /// Field types that mention a type parameter, e.g. `Vec<T>` but not `String`.
+    ///
+    /// Only those need a bound, bounding concrete types would make recursive types
+    /// such as a tree of nodes overflow the trait solver.
+    pub fn generic_types<'t>(&self, types: &'t [syn::Type]) -> Vec<&'t syn::Type> {
+        let params: Vec<&syn::Ident> = self
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect();
+
+        types
+            .iter()
+            .filter(|ty| mentions(ty.to_token_stream(), &params))
+            .collect()
+    }
+
+    /// Field types that do not contain the type itself, e.g. `Address` but not
+    /// `Vec<Self>` in a tree. Those hold whenever the impl does, and bounding them
+    /// would overflow the trait solver.
+    pub fn non_recursive_types<'t>(
+        &self,
+        name: &syn::Ident,
+        types: &'t [syn::Type],
+    ) -> Vec<&'t syn::Type> {
+        let this = syn::Ident::new("Self", name.span());
+
+        types
+            .iter()
+            .filter(|ty| !mentions(ty.to_token_stream(), &[name, &this]))
+            .collect()
+    }
+
+    /// `impl<...>` parameters, with `__C: ?Sized` added for context-generic impls.
+    pub fn impl_generics(&self, with_context: bool) -> TokenStream {
+        let mut generics = self.generics.clone();
+        if with_context {
+            generics.params.push(syn::parse_quote! { __C: ?Sized });
+        }
+
+        let (impl_generics, _, _) = generics.split_for_impl();
+        impl_generics.to_token_stream()
+    }
+
+    /// Parameters applied to the type, e.g. `Page<'a, T>`.
+    pub fn ty_generics(&self) -> TokenStream {
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        ty_generics.to_token_stream()
+    }
+
+    /// The where clause of the input extended with `predicates`.
+    pub fn where_clause(&self, predicates: &[TokenStream]) -> TokenStream {
+        let existing = self
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|clause| clause.predicates.iter());
+
+        quote! { where #(#existing,)* #(#predicates,)* }
+    }
+}
+
+fn mentions(tokens: TokenStream, params: &[&syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.iter().any(|param| **param == ident),
+        TokenTree::Group(group) => mentions(group.stream(), params),
+        _ => false,
+    })
+}
@@ -0,0 +1,56 @@
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+
+/// Struct-level settings shared by every field rule, e.g. `#[valid(context = MyCtx)]`.
+#[derive(Default)]
+pub struct Container {
+    pub context: Option<syn::Type>,
+    /// Replaces the inferred bounds, e.g. `#[valid(bound = "T: Validate + Clone")]`.
+    pub bound: Option<Vec<syn::WherePredicate>>,
+}
+
+impl Container {
+    /// Consumes `expr` if it is a struct-level setting, returns `false` for rules.
+    pub fn parse(&mut self, expr: &syn::Expr) -> syn::Result<bool> {
+        let syn::Expr::Assign(assign) = expr else {
+            return Ok(false);
+        };
+
+        let syn::Expr::Path(p) = &*assign.left else {
+            return Ok(false);
+        };
+
+        match p.path.segments.last().unwrap().ident.to_string().as_str() {
+            "context" => {
+                if self.context.is_some() {
+                    return Err(syn::Error::new_spanned(expr, "duplicate `context` setting"));
+                }
+                self.context = Some(syn::parse2(assign.right.to_token_stream())?);
+                Ok(true)
+            }
+            "bound" => {
+                if self.bound.is_some() {
+                    return Err(syn::Error::new_spanned(expr, "duplicate `bound` setting"));
+                }
+
+                let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }) = &*assign.right
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &assign.right,
+                        "`bound` must be a string literal, e.g. `bound = \"T: Validate\"`",
+                    ));
+                };
+
+                let predicates = lit.parse_with(
+                    Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated,
+                )?;
+                self.bound = Some(predicates.into_iter().collect());
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
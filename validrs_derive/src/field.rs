@@ -0,0 +1,132 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt as _;
+use syn::spanned::Spanned;
+
+/// A field being validated: its name for messages, how generated code reads it and
+/// the path its errors are reported under.
+#[derive(Clone)]
+pub struct Field {
+    pub name: String,
+    pub span: Span,
+    pub access: TokenStream,
+    pub path: TokenStream,
+}
+
+/// Fields visible to the rules of a struct or of a single enum variant.
+#[derive(Default)]
+pub struct Scope {
+    pub fields: Vec<Field>,
+}
+
+impl Scope {
+    /// Fields of a struct, read through `self`. Tuple fields are read as `self.0` and
+    /// reported under their index.
+    pub fn of_struct(fields: &syn::Fields) -> Self {
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let member = member(index, field);
+                Field {
+                    access: quote! { self.#member },
+                    ..Field::new(index, field)
+                }
+            })
+            .collect();
+
+        Self { fields }
+    }
+
+    /// Fields of an enum variant, read through the bindings of [`Scope::pattern`] and
+    /// reported under the variant name, e.g. `Card.number` or `Card[0]`.
+    pub fn of_variant(variant: &syn::Variant) -> Self {
+        let prefix = variant.ident.unraw().to_string();
+        let fields = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let field = Field::new(index, field);
+                let binding = binding(index, &variant.fields);
+                let path = &field.path;
+                Field {
+                    access: quote! { (*#binding) },
+                    path: quote! { #path.prefixed(&validrs::path::Path::field(#prefix)) },
+                    ..field
+                }
+            })
+            .collect();
+
+        Self { fields }
+    }
+
+    /// Pattern matching `variant` and binding every field read by [`Scope::of_variant`].
+    pub fn pattern(variant: &syn::Variant) -> TokenStream {
+        let ident = &variant.ident;
+        let bindings = (0..variant.fields.len()).map(|index| binding(index, &variant.fields));
+
+        match &variant.fields {
+            syn::Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                quote! { Self::#ident { #(#names: #bindings,)* } }
+            }
+            syn::Fields::Unnamed(_) => quote! { Self::#ident(#(#bindings,)*) },
+            syn::Fields::Unit => quote! { Self::#ident },
+        }
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+impl Field {
+    /// Name and path of the field, its access depends on where it is read from. Raw
+    /// identifiers are named without their `r#`, e.g. `type` for `r#type`.
+    fn new(index: usize, field: &syn::Field) -> Self {
+        match &field.ident {
+            Some(ident) => {
+                let name = ident.unraw().to_string();
+                Self {
+                    span: ident.span(),
+                    access: TokenStream::new(),
+                    path: quote! { validrs::path::Path::field(#name) },
+                    name,
+                }
+            }
+            None => Self {
+                name: index.to_string(),
+                span: field.ty.span(),
+                access: TokenStream::new(),
+                path: quote! { validrs::path::Path::index(#index) },
+            },
+        }
+    }
+}
+
+fn member(index: usize, field: &syn::Field) -> syn::Member {
+    match &field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(index)),
+    }
+}
+
+/// Prefixed so that bindings never shadow the locals of the generated code.
+fn binding(index: usize, fields: &syn::Fields) -> syn::Ident {
+    match fields
+        .iter()
+        .nth(index)
+        .and_then(|field| field.ident.as_ref())
+    {
+        Some(ident) => format_ident!("__self_{}", ident),
+        None => format_ident!("__self_{}", index),
+    }
+}
@@ -1,200 +1,723 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{ToTokens, quote};
 use syn::{DeriveInput, parse_macro_input};
 
+use crate::bounds::Bounds;
+use crate::container::Container;
+use crate::field::{Field, Scope};
+use crate::modifiers::Modifiers;
+use crate::rules::color::Color;
+use crate::rules::compare::{Compare, CompareKind};
 use crate::rules::contains::Contains;
-use crate::rules::required::Required;
+use crate::rules::custom::Custom;
+use crate::rules::ip::Ip;
+use crate::rules::nested::Nested;
+use crate::rules::presence::Presence;
+use crate::rules::regex::Regex;
+use crate::rules::reject::Reject;
+use crate::rules::required::{Required, RequiredIf};
+use crate::rules::schema::Schema;
+use crate::rules::simple::Simple;
 use crate::rules::{length::Length, range::Range};
-use crate::utils::compile_error;
+use crate::utils::{at_rule, reject_duplicates, unknown};
 
+mod bounds;
+mod container;
+mod field;
+mod modifiers;
 mod rules;
 mod utils;
 
+/// Field rules other than the [`Simple`] ones, used to suggest a name for typos.
+const FIELD_RULES: &[&str] = &[
+    "len",
+    "rng",
+    "contains",
+    "required",
+    "required_if",
+    "required_unless",
+    "ip",
+    "color",
+    "regex",
+    "custom",
+    "async_custom",
+    "nested",
+    "must_match",
+    "gt_field",
+    "lt_field",
+];
+
+const CONTAINER_RULES: &[&str] = &[
+    "schema",
+    "one_of_required",
+    "mutually_exclusive",
+    "exactly_one",
+];
+
+const CONTAINER_SETTINGS: &[&str] = &["context", "bound"];
+
+const MODIFIERS: &[&str] = &["when", "groups", "none"];
+
 #[proc_macro_derive(Valid, attributes(valid))]
 pub fn valid(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
-    let name = input.ident;
+    match expand(&input) {
+        Ok(r#gen) => r#gen.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Statements generated for the rules of a struct or of a single enum variant.
+#[derive(Default)]
+struct Validations {
+    sync: Vec<proc_macro2::TokenStream>,
+    asynchronous: Vec<proc_macro2::TokenStream>,
+    nested_types: Vec<syn::Type>,
+    /// The sync statements of each field, run alone by `validate_fields`.
+    fields: Vec<(String, Vec<proc_macro2::TokenStream>)>,
+    /// Statements of `validate_fields`, each running the rules of a listed field.
+    fields_checks: Vec<proc_macro2::TokenStream>,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+
+    let mut validations = Validations::default();
+    let mut schemas = Vec::new();
+    let mut container = Container::default();
+
+    // Struct-level rules can only name fields of structs, enums have a field set per variant.
+    let scope = match &input.data {
+        syn::Data::Struct(data) => Scope::of_struct(&data.fields),
+        syn::Data::Enum(_) => Scope::default(),
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Valid cannot be derived for unions",
+            ));
+        }
+    };
+
+    for attr in valid_attrs(&input.attrs) {
+        let mut modifiers = Modifiers::default();
+        let mut checks = Vec::new();
+
+        for expr in parse_valid(attr)? {
+            if container.parse(&expr)? || modifiers.parse(&expr)? {
+                continue;
+            }
+            checks.push(container_rule(&expr, &scope)?);
+        }
+
+        modifiers.fields_only()?;
+        if !checks.is_empty() {
+            schemas.push(modifiers.wrap(checks));
+        }
+    }
+
+    match &input.data {
+        syn::Data::Struct(data) => {
+            field_validations(&data.fields, &scope, &container, &mut validations)?;
+        }
+        syn::Data::Enum(data) => {
+            let mut arms = Vec::new();
+            let mut async_arms = Vec::new();
+            let mut field_arms = Vec::new();
+
+            for variant in &data.variants {
+                let scope = Scope::of_variant(variant);
+                let mut variant_validations = Validations::default();
+                variant_rules(variant, &mut variant_validations)?;
+                field_validations(
+                    &variant.fields,
+                    &scope,
+                    &container,
+                    &mut variant_validations,
+                )?;
+
+                let pattern = Scope::pattern(variant);
+                let sync = &variant_validations.sync;
+                let asynchronous = &variant_validations.asynchronous;
+                arms.push(quote! { #pattern => { #(#sync)* } });
+                async_arms.push(quote! { #pattern => { #(#asynchronous)* } });
+                let fields = listed_fields(&variant_validations.fields);
+                field_arms.push(quote! { #pattern => { #(#fields)* } });
+                validations
+                    .nested_types
+                    .extend(variant_validations.nested_types);
+            }
+
+            if !arms.is_empty() {
+                validations.sync.push(quote! { match self { #(#arms)* } });
+                validations
+                    .asynchronous
+                    .push(quote! { match self { #(#async_arms)* } });
+                validations
+                    .fields_checks
+                    .push(quote! { match self { #(#field_arms)* } });
+            }
+        }
+        syn::Data::Union(_) => unreachable!(),
+    }
+
+    let sync = &validations.sync;
+    let body = quote! {
+        let mut errors = validrs::error::ValidationErrors::new();
+
+        #(#sync)*
+
+        #(#schemas)*
+
+        errors.into_result()
+    };
+
+    // Only the rules of the listed fields run, of the active variant for enums.
+    if let syn::Data::Struct(_) = &input.data {
+        validations.fields_checks = listed_fields(&validations.fields);
+    }
+    let fields_checks = &validations.fields_checks;
+    let fields_body = quote! {
+        let group = validrs::validate::Group::Default;
+        let mut errors = validrs::error::ValidationErrors::new();
+
+        #(#fields_checks)*
+
+        errors.into_result()
+    };
 
-    let mut validations = Vec::new();
+    let asynchronous = &validations.asynchronous;
+    let async_body = quote! {
+        let mut errors = validrs::error::ValidationErrors::new();
 
-    if let syn::Data::Struct(data) = &input.data {
-        for field in &data.fields {
-            let field_name = field.ident.as_ref().unwrap();
+        #(#asynchronous)*
 
-            for attr in &field.attrs {
-                if !attr.path().is_ident("valid") {
-                    continue;
+        errors.into_result()
+    };
+
+    let bounds = Bounds::new(&input.generics);
+    let generic_types = bounds.generic_types(&validations.nested_types);
+    let impl_generics = bounds.impl_generics(false);
+    let context_generics = bounds.impl_generics(true);
+    let ty_generics = bounds.ty_generics();
+
+    let user_bounds = container.bound.as_ref().map(|bound| {
+        bound
+            .iter()
+            .map(ToTokens::to_token_stream)
+            .collect::<Vec<_>>()
+    });
+
+    let r#gen = match &container.context {
+        Some(context) => {
+            let validate_fields = quote! {
+                #[allow(unused_variables)]
+                fn validate_fields_with_context(
+                    &self,
+                    ctx: &#context,
+                    fields: &[&str],
+                ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                    #fields_body
                 }
+            };
+
+            let sync_where = bounds.where_clause(&user_bounds.clone().unwrap_or_else(|| {
+                generic_types
+                    .iter()
+                    .map(|ty| quote! { #ty: validrs::validate::ValidateWithContext<#context> })
+                    .collect()
+            }));
+            let async_where = bounds.where_clause(&user_bounds.clone().unwrap_or_else(|| {
+                generic_types
+                    .iter()
+                    .map(|ty| quote! { #ty: validrs::validate::AsyncValidate<#context> })
+                    .collect()
+            }));
+
+            quote! {
+                impl #impl_generics validrs::validate::ValidateWithContext<#context> for #name #ty_generics
+                #sync_where
+                {
+                    fn validate_with_context(
+                        &self,
+                        ctx: &#context,
+                    ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        self.validate_group_with_context(ctx, validrs::validate::Group::Default)
+                    }
 
-                let validators = match attr.parse_args_with(
-                    syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated,
-                ) {
-                    Ok(list) => list,
-                    Err(err) => return err.to_compile_error().into(),
-                };
-
-                for expr in validators {
-                    match expr {
-                        syn::Expr::Call(ref call) => {
-                            let validator_name = match &*call.func {
-                                syn::Expr::Path(p) => {
-                                    p.path.segments.last().unwrap().ident.to_string()
-                                }
-                                _ => continue,
-                            };
-
-                            match validator_name.as_str() {
-                                "len" => {
-                                    match Length::parse(call, field_name) {
-                                        Ok(length) => match length.check(field_name) {
-                                            Some(token) => {
-                                                validations.push(token);
-                                            }
-                                            None => continue,
-                                        },
-                                        Err(err) => {
-                                            return compile_error(field, err).into();
-                                        }
-                                    };
-                                }
-                                "rng" => match Range::parse(call, field_name) {
-                                    Ok(rule) => {
-                                        if let Some(token) = rule.check(field_name) {
-                                            validations.push(token);
-                                        }
-                                    }
-                                    Err(err) => {
-                                        return compile_error(field, err).into();
-                                    }
-                                },
-                                "contains" => match Contains::parse(call, field_name) {
-                                    Ok(rule) => {
-                                        if let Some(token) = rule.check(field_name) {
-                                            validations.push(token);
-                                        }
-                                    }
-                                    Err(err) => {
-                                        return compile_error(field, err).into();
-                                    }
-                                },
-                                "required" => match Required::parse(call, field_name) {
-                                    Ok(rule) => {
-                                        if let Some(token) = rule.check(field_name) {
-                                            validations.push(token);
-                                        }
-                                    }
-                                    Err(err) => {
-                                        return compile_error(field, err).into();
-                                    }
-                                },
-                                _ => {
-                                    return compile_error(
-                                        call,
-                                        format!("Unknown validator: {}", validator_name),
-                                    )
-                                    .into();
-                                }
-                            }
-                        }
-                        syn::Expr::Path(ref path) => {
-                            let validator_name =
-                                &*path.path.segments.last().unwrap().ident.to_string();
-
-                            match validator_name {
-                                "required" => {
-                                    if let Some(token) = Required::default().check(field_name) {
-                                        validations.push(token);
-                                    }
-                                }
-                                _ => {
-                                    return compile_error(
-                                        path,
-                                        format!("Unknown validator: {}", validator_name),
-                                    )
-                                    .into();
-                                }
-                            }
-                        }
-                        _ => {
-                            return compile_error(field_name, format!("Unknown validator")).into();
-                        }
+                    #[allow(unused_variables)]
+                    fn validate_group_with_context(
+                        &self,
+                        ctx: &#context,
+                        group: validrs::validate::Group,
+                    ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        #body
+                    }
+
+                    #validate_fields
+                }
+
+                impl #impl_generics validrs::validate::AsyncValidate<#context> for #name #ty_generics
+                #async_where
+                {
+                    #[allow(unused_variables)]
+                    async fn validate_async_rules(
+                        &self,
+                        ctx: &#context,
+                        group: validrs::validate::Group,
+                    ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        #async_body
                     }
-                    // if let syn::Expr::Call(ref call) = expr {
-                    //     let validator_name = match &*call.func {
-                    //         syn::Expr::Path(p) => p.path.segments.last().unwrap().ident.to_string(),
-                    //         _ => continue,
-                    //     };
-
-                    //     match validator_name.as_str() {
-                    //         "len" => {
-                    //             match Length::parse(call, field_name) {
-                    //                 Ok(length) => match length.check(field_name) {
-                    //                     Some(token) => {
-                    //                         validations.push(token);
-                    //                     }
-                    //                     None => continue,
-                    //                 },
-                    //                 Err(err) => {
-                    //                     return compile_error(field, err).into();
-                    //                 }
-                    //             };
-                    //         }
-                    //         "rng" => match Range::parse(call, field_name) {
-                    //             Ok(rule) => {
-                    //                 if let Some(token) = rule.check(field_name) {
-                    //                     validations.push(token);
-                    //                 }
-                    //             }
-                    //             Err(err) => {
-                    //                 return compile_error(field, err).into();
-                    //             }
-                    //         },
-                    //         "contains" => match Contains::parse(call, field_name) {
-                    //             Ok(rule) => {
-                    //                 if let Some(token) = rule.check(field_name) {
-                    //                     validations.push(token);
-                    //                 }
-                    //             }
-                    //             Err(err) => {
-                    //                 return compile_error(field, err).into();
-                    //             }
-                    //         },
-                    //         "required" => match Required::parse(call, field_name) {
-                    //             Ok(rule) => {
-                    //                 if let Some(token) = rule.check(field_name) {
-                    //                     validations.push(token);
-                    //                 }
-                    //             }
-                    //             Err(err) => {
-                    //                 return compile_error(field, err).into();
-                    //             }
-                    //         },
-                    //         _ => {
-                    //             return compile_error(
-                    //                 call,
-                    //                 format!("Unknown validator: {}", validator_name),
-                    //             )
-                    //             .into();
-                    //         }
-                    //     }
-                    // }
                 }
             }
         }
+        None => {
+            let sync_bounds = user_bounds.clone().unwrap_or_else(|| {
+                generic_types
+                    .iter()
+                    .map(|ty| quote! { #ty: validrs::validate::Validate })
+                    .collect()
+            });
+            let sync_where = bounds.where_clause(&sync_bounds);
+
+            // Nested types may only implement `AsyncValidate` for their own context, so
+            // they are bounded whether or not they are generic.
+            let mut async_bounds = user_bounds.unwrap_or_default();
+            async_bounds.extend(
+                bounds
+                    .non_recursive_types(name, &validations.nested_types)
+                    .into_iter()
+                    .map(|ty| quote! { #ty: validrs::validate::AsyncValidate<__C> }),
+            );
+            let async_where = bounds.where_clause(&async_bounds);
+
+            let validate_fields = quote! {
+                #[allow(unused_variables)]
+                fn validate_fields(
+                    &self,
+                    fields: &[&str],
+                ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                    #fields_body
+                }
+            };
+
+            quote! {
+                impl #impl_generics validrs::validate::Validate for #name #ty_generics
+                #sync_where
+                {
+                    fn validate(&self) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        self.validate_group(validrs::validate::Group::Default)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn validate_group(
+                        &self,
+                        group: validrs::validate::Group,
+                    ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        #body
+                    }
+
+                    #validate_fields
+                }
+
+                impl #context_generics validrs::validate::ValidateWithContext<__C> for #name #ty_generics
+                #sync_where
+                {
+                    fn validate_with_context(
+                        &self,
+                        _ctx: &__C,
+                    ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        validrs::validate::Validate::validate(self)
+                    }
+
+                    fn validate_group_with_context(
+                        &self,
+                        _ctx: &__C,
+                        group: validrs::validate::Group,
+                    ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        validrs::validate::Validate::validate_group(self, group)
+                    }
+
+                    fn validate_fields_with_context(
+                        &self,
+                        _ctx: &__C,
+                        fields: &[&str],
+                    ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        validrs::validate::Validate::validate_fields(self, fields)
+                    }
+                }
+
+                impl #context_generics validrs::validate::AsyncValidate<__C> for #name #ty_generics
+                #async_where
+                {
+                    #[allow(unused_variables)]
+                    async fn validate_async_rules(
+                        &self,
+                        ctx: &__C,
+                        group: validrs::validate::Group,
+                    ) -> ::core::result::Result<(), validrs::error::ValidationErrors> {
+                        #async_body
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(r#gen)
+}
+
+fn valid_attrs(attrs: &[syn::Attribute]) -> impl Iterator<Item = &syn::Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("valid"))
+}
+
+fn parse_valid(
+    attr: &syn::Attribute,
+) -> syn::Result<syn::punctuated::Punctuated<syn::Expr, syn::Token![,]>> {
+    attr.parse_args_with(syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+}
+
+/// Collects the field rules of a struct or an enum variant into `validations`.
+fn field_validations(
+    fields: &syn::Fields,
+    scope: &Scope,
+    container: &Container,
+    validations: &mut Validations,
+) -> syn::Result<()> {
+    for (field, scoped) in fields.iter().zip(&scope.fields) {
+        let start = validations.sync.len();
+        for attr in valid_attrs(&field.attrs) {
+            let mut modifiers = Modifiers::default();
+            let mut checks = Vec::new();
+            let mut async_checks = Vec::new();
+            let mut nested_checks = Vec::new();
+            let mut nested_async_checks = Vec::new();
+
+            let mut rules = Vec::new();
+            for expr in parse_valid(attr)? {
+                if !modifiers.parse(&expr)? {
+                    rules.push(expr);
+                }
+            }
+
+            for expr in &rules {
+                let rule = field_rule(expr, scoped, scope, &modifiers, container)?;
+                if rule.nested {
+                    validations.nested_types.push(field.ty.clone());
+                    nested_checks.push(rule.sync);
+                    nested_async_checks.push(rule.asynchronous);
+                } else {
+                    checks.push(rule.sync);
+                    async_checks.push(rule.asynchronous);
+                }
+            }
+
+            // Nested values run in every group, so a missing one is reported there.
+            let nested = nested_checks.iter().any(|check| !check.is_empty());
+            let checks = modifiers.reject_none(scoped, checks, !nested);
+            let nested_checks = modifiers.reject_none(scoped, nested_checks, true);
+            let async_checks = modifiers.reject_none(scoped, async_checks, false);
+            let nested_async_checks = modifiers.reject_none(scoped, nested_async_checks, false);
+            validations
+                .sync
+                .push(skip_absent(scoped, modifiers.wrap(checks)));
+            validations
+                .sync
+                .push(skip_absent(scoped, modifiers.wrap_nested(nested_checks)));
+            validations
+                .asynchronous
+                .push(skip_absent(scoped, modifiers.wrap(async_checks)));
+            validations.asynchronous.push(skip_absent(
+                scoped,
+                modifiers.wrap_nested(nested_async_checks),
+            ));
+        }
+        let checks = validations.sync[start..].to_vec();
+        validations.fields.push((scoped.name.clone(), checks));
+    }
+
+    Ok(())
+}
+
+/// Runs the statements of each field only when `fields` lists its name.
+fn listed_fields(
+    fields: &[(String, Vec<proc_macro2::TokenStream>)],
+) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|(name, checks)| {
+            quote! {
+                if fields.contains(&#name) {
+                    #(#checks)*
+                }
+            }
+        })
+        .collect()
+}
+
+/// Collects the variant-level rules of an enum variant, e.g. `#[valid(reject)]`.
+fn variant_rules(variant: &syn::Variant, validations: &mut Validations) -> syn::Result<()> {
+    for attr in valid_attrs(&variant.attrs) {
+        let mut modifiers = Modifiers::default();
+        let mut checks = Vec::new();
+
+        for expr in parse_valid(attr)? {
+            if modifiers.parse(&expr)? {
+                continue;
+            }
+
+            let check = match &expr {
+                syn::Expr::Path(p) if p.path.is_ident("reject") => Reject::default(),
+                syn::Expr::Call(call) if matches!(&*call.func, syn::Expr::Path(p) if p.path.is_ident("reject")) =>
+                {
+                    reject_duplicates(call)?;
+                    Reject::parse(call)?
+                }
+                expr => {
+                    let name = match expr {
+                        syn::Expr::Call(call) => rule_name(&call.func)?,
+                        expr => rule_name(expr)?,
+                    };
+                    let known: Vec<&str> = ["reject"].iter().chain(MODIFIERS).copied().collect();
+                    return Err(unknown("variant validator", expr, &name, &known));
+                }
+            };
+            checks.push(check.check(&variant.ident));
+        }
+
+        modifiers.fields_only()?;
+        validations.sync.push(modifiers.wrap(checks));
+    }
+
+    Ok(())
+}
+
+/// Statements generated for a single field rule, split by the method running them.
+#[derive(Default)]
+struct FieldRule {
+    sync: proc_macro2::TokenStream,
+    asynchronous: proc_macro2::TokenStream,
+    nested: bool,
+}
+
+/// Turns a single entry of `#[valid(...)]`, either `rule` or `rule(args)`, into the
+/// statements that run it.
+fn field_rule(
+    expr: &syn::Expr,
+    field: &Field,
+    scope: &Scope,
+    modifiers: &Modifiers,
+    container: &Container,
+) -> syn::Result<FieldRule> {
+    if let syn::Expr::Call(call) = expr {
+        reject_duplicates(call)?;
+    }
+
+    if let Some(kind) = compare_kind(expr) {
+        let compare = Compare::parse(kind, expr, field, scope)?;
+        let check = compare
+            .check(field)
+            .map(|check| skip_missing(modifiers, &[field, compare.other()], check))
+            .map(|check| at_rule(check, expr));
+        return Ok(FieldRule {
+            sync: check.map(|check| collect(field, check)).unwrap_or_default(),
+            ..Default::default()
+        });
+    }
+
+    let (validator_name, call) = match expr {
+        syn::Expr::Call(call) => (rule_name(&call.func)?, Some(call)),
+        expr => (rule_name(expr)?, None),
+    };
+
+    let check = match (validator_name.as_str(), call) {
+        ("len", Some(call)) => Length::parse(call, field)?.check(field),
+        ("rng", Some(call)) => Range::parse(call, field)?.check(field),
+        ("contains", Some(call)) => Contains::parse(call, field)?.check(field),
+        ("required", Some(call)) => Required::parse(call, field)?.check(field),
+        ("required", None) => Required::default().check(field),
+        ("required_if", Some(call)) => RequiredIf::parse(call, false, scope)?.check(field),
+        ("required_unless", Some(call)) => RequiredIf::parse(call, true, scope)?.check(field),
+        ("ip", Some(call)) => Ip::parse(call, field)?.check(field),
+        ("ip", None) => Ip::default().check(field),
+        ("color", Some(call)) => Color::parse(call, field)?.check(field),
+        ("color", None) => Color::default().check(field),
+        ("regex", Some(call)) => Regex::parse(call, field)?.check(field),
+        ("custom", Some(call)) => Custom::parse(call, false)?
+            .check(field)
+            .map(|check| skip_missing(modifiers, &[field], check)),
+        ("async_custom", Some(call)) => {
+            let check = Custom::parse(call, true)?
+                .check(field)
+                .map(|check| skip_missing(modifiers, &[field], check))
+                .map(|check| at_rule(check, expr));
+            return Ok(FieldRule {
+                asynchronous: check.map(|check| collect(field, check)).unwrap_or_default(),
+                ..Default::default()
+            });
+        }
+        ("nested", None) => {
+            return Ok(FieldRule {
+                sync: at_rule(Nested.check(field, container), expr),
+                asynchronous: at_rule(Nested.check_async(field, container), expr),
+                nested: true,
+            });
+        }
+        ("nested", Some(call)) => {
+            return Err(syn::Error::new_spanned(call, "`nested` takes no arguments"));
+        }
+        (name, call) => match (Simple::find(name), call) {
+            (Some(rule), Some(call)) => Simple::parse(rule, call, field)?.check(field),
+            (Some(rule), None) => Simple::new(rule).check(field),
+            (None, None) if FIELD_RULES.contains(&name) => {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    format!("`{name}` requires arguments, e.g. `{name}(...)`"),
+                ));
+            }
+            (None, _) => {
+                let known: Vec<&str> = FIELD_RULES
+                    .iter()
+                    .copied()
+                    .chain(Simple::names())
+                    .chain(MODIFIERS.iter().copied())
+                    .collect();
+                return Err(unknown("validator", expr, name, &known));
+            }
+        },
+    };
+
+    Ok(FieldRule {
+        sync: check
+            .map(|check| collect(field, at_rule(check, expr)))
+            .unwrap_or_default(),
+        ..Default::default()
+    })
+}
+
+/// Name of a rule written as `rule`, `rule(...)` or `rule = value`.
+fn rule_name(expr: &syn::Expr) -> syn::Result<String> {
+    let path = match expr {
+        syn::Expr::Assign(assign) => &*assign.left,
+        expr => expr,
+    };
+
+    match path {
+        syn::Expr::Path(p) if p.path.get_ident().is_some() => {
+            Ok(p.path.get_ident().unwrap().to_string())
+        }
+        _ => Err(syn::Error::new_spanned(expr, "expected a validator")),
+    }
+}
+
+/// Rules handed the whole value, comparisons and custom functions, pass when one of
+/// `fields` is `None` or `Patch::Null` unless the attribute sets `none = reject`.
+fn skip_missing(
+    modifiers: &Modifiers,
+    fields: &[&Field],
+    check: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if modifiers.rejects_none() {
+        return check;
+    }
+
+    let accesses = fields.iter().map(|field| &field.access);
+
+    quote! {
+        {
+            use validrs::rules::{IsOptional as _, IsPresent as _};
+            if #((&validrs::rules::MaybeOptional(&#accesses)).is_missing())||* {
+                validrs::error::Result::<()>::Ok(())
+            } else {
+                #check
+            }
+        }
     }
+}
 
-    let r#gen = quote! {
-        impl validrs::validate::Validate for #name {
-            fn validate(&self) -> validrs::error::Result<()> {
-                #(#validations)*
+/// Fields that were not sent in a PATCH body are not validated at all. Whether the
+/// field is a `Patch` is left to the trait solver, so aliases and re-exports work.
+fn skip_absent(field: &Field, checks: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if checks.is_empty() {
+        return checks;
+    }
+
+    let field_access = &field.access;
 
-                Ok(())
+    quote! {
+        {
+            use validrs::patch::{IsNotPatch as _, IsPatch as _};
+            if !(&validrs::patch::MaybePatch(&#field_access)).is_absent() {
+                #checks
             }
         }
+    }
+}
+
+/// Cross-field comparisons accept both the `gt_field = "other"` and the
+/// `gt_field(other = "other")` forms.
+fn compare_kind(expr: &syn::Expr) -> Option<CompareKind> {
+    let path = match expr {
+        syn::Expr::Assign(assign) => &*assign.left,
+        syn::Expr::Call(call) => &*call.func,
+        _ => return None,
+    };
+
+    let syn::Expr::Path(p) = path else {
+        return None;
     };
 
-    r#gen.into()
+    match p.path.segments.last()?.ident.to_string().as_str() {
+        "must_match" => Some(CompareKind::MustMatch),
+        "gt_field" => Some(CompareKind::GreaterThan),
+        "lt_field" => Some(CompareKind::LessThan),
+        _ => None,
+    }
+}
+
+/// Turns a single entry of a struct-level `#[valid(...)]` into the statement that runs it.
+fn container_rule(expr: &syn::Expr, scope: &Scope) -> syn::Result<proc_macro2::TokenStream> {
+    let name = rule_name(match expr {
+        syn::Expr::Call(call) => &call.func,
+        expr => expr,
+    })?;
+
+    let call = match expr {
+        syn::Expr::Call(call) if CONTAINER_RULES.contains(&name.as_str()) => call,
+        _ if CONTAINER_RULES.contains(&name.as_str()) => {
+            return Err(syn::Error::new_spanned(
+                expr,
+                format!("`{name}` requires arguments, e.g. `{name}(...)`"),
+            ));
+        }
+        _ => {
+            let known: Vec<&str> = CONTAINER_RULES
+                .iter()
+                .chain(CONTAINER_SETTINGS)
+                .chain(MODIFIERS)
+                .copied()
+                .collect();
+            return Err(unknown("validator", expr, &name, &known));
+        }
+    };
+
+    reject_duplicates(call)?;
+
+    match name.as_str() {
+        "schema" => Ok(Schema::parse(call, scope)?.check()),
+        "one_of_required" => Ok(Presence::parse("OneOfRequired", call, scope)?.check()),
+        "mutually_exclusive" => Ok(Presence::parse("MutuallyExclusive", call, scope)?.check()),
+        _ => Ok(Presence::parse("ExactlyOne", call, scope)?.check()),
+    }
+}
+
+fn collect(field: &Field, check: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let path = &field.path;
+
+    quote! {
+        if let Err(err) = #check {
+            errors.add(#path, validrs::error::Error::from(err));
+        }
+    }
 }
 
 // #[proc_macro_derive(Validate, attributes(validate))]
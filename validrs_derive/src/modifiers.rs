@@ -0,0 +1,186 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field::Field;
+use crate::utils::unknown;
+
+/// Entries of a `#[valid(...)]` attribute that change when its rules run rather
+/// than adding a rule, e.g. `#[valid(len(min = 1), when = is_business, groups = [create])]`.
+#[derive(Default)]
+pub struct Modifiers {
+    when: Option<syn::Expr>,
+    groups: Option<Vec<TokenStream>>,
+    /// `none = reject` fails the attribute's rules on `None` instead of passing them.
+    none: Option<(syn::Expr, bool)>,
+}
+
+impl Modifiers {
+    /// Consumes `expr` if it is a modifier, returns `false` for rules.
+    pub fn parse(&mut self, expr: &syn::Expr) -> syn::Result<bool> {
+        let syn::Expr::Assign(assign) = expr else {
+            return Ok(false);
+        };
+
+        let syn::Expr::Path(p) = &*assign.left else {
+            return Ok(false);
+        };
+
+        match p.path.segments.last().unwrap().ident.to_string().as_str() {
+            "when" => {
+                if self.when.is_some() {
+                    return Err(syn::Error::new_spanned(expr, "duplicate `when` modifier"));
+                }
+                self.when = Some((*assign.right).clone());
+                Ok(true)
+            }
+            "groups" => {
+                if self.groups.is_some() {
+                    return Err(syn::Error::new_spanned(expr, "duplicate `groups` modifier"));
+                }
+                self.groups = Some(parse_groups(&assign.right)?);
+                Ok(true)
+            }
+            "none" => {
+                if self.none.is_some() {
+                    return Err(syn::Error::new_spanned(expr, "duplicate `none` modifier"));
+                }
+                self.none = Some((expr.clone(), parse_none(&assign.right)?));
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// `none` only applies to field rules, struct and variant rules have no value.
+    pub fn fields_only(&self) -> syn::Result<()> {
+        match &self.none {
+            Some((expr, _)) => Err(syn::Error::new_spanned(
+                expr,
+                "`none` only applies to field rules",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn rejects_none(&self) -> bool {
+        matches!(self.none, Some((_, true)))
+    }
+
+    /// With `none = reject`, the rules only run on a present value and a missing one
+    /// fails with [`Error::Required`] when `report` is set. One block of the attribute
+    /// reports so the error is not added twice.
+    ///
+    /// [`Error::Required`]: validrs::error::Error::Required
+    pub fn reject_none(
+        &self,
+        field: &Field,
+        checks: Vec<TokenStream>,
+        report: bool,
+    ) -> Vec<TokenStream> {
+        if !self.rejects_none() || checks.iter().all(TokenStream::is_empty) {
+            return checks;
+        }
+
+        let access = &field.access;
+        let path = &field.path;
+
+        let missing = match report {
+            true => quote! { errors.add(#path, validrs::error::Error::Required); },
+            false => TokenStream::new(),
+        };
+
+        vec![quote! {
+            if validrs::rules::Optional::is_none(&#access) {
+                #missing
+            } else {
+                #(#checks)*
+            }
+        }]
+    }
+
+    /// Runs `checks` only for the requested groups, [`Group::Default`] when none are given.
+    pub fn wrap(&self, checks: Vec<TokenStream>) -> TokenStream {
+        if checks.iter().all(TokenStream::is_empty) {
+            return TokenStream::new();
+        }
+
+        let default = [quote! { validrs::validate::Group::Default }];
+        let groups = self.groups.as_deref().unwrap_or(&default);
+
+        self.wrap_when(quote! {
+            if matches!(group, #(#groups)|*) {
+                #(#checks)*
+            }
+        })
+    }
+
+    /// Nested values run in every group unless `groups` is set, the requested group is
+    /// passed down to them.
+    pub fn wrap_nested(&self, checks: Vec<TokenStream>) -> TokenStream {
+        if checks.iter().all(TokenStream::is_empty) {
+            return TokenStream::new();
+        }
+
+        match &self.groups {
+            Some(_) => self.wrap(checks),
+            None => self.wrap_when(quote! { #(#checks)* }),
+        }
+    }
+
+    fn wrap_when(&self, checks: TokenStream) -> TokenStream {
+        match &self.when {
+            Some(predicate) => quote! {
+                if #predicate(self) {
+                    #checks
+                }
+            },
+            None => checks,
+        }
+    }
+}
+
+fn parse_none(expr: &syn::Expr) -> syn::Result<bool> {
+    let name = match expr {
+        syn::Expr::Path(p) => p.path.get_ident().map(|ident| ident.to_string()),
+        _ => None,
+    };
+
+    match name.as_deref() {
+        Some("pass") => Ok(false),
+        Some("reject") => Ok(true),
+        Some(name) => Err(unknown("`none` policy", expr, name, &["pass", "reject"])),
+        None => Err(syn::Error::new_spanned(
+            expr,
+            "`none` expects `pass` or `reject`",
+        )),
+    }
+}
+
+fn parse_groups(expr: &syn::Expr) -> syn::Result<Vec<TokenStream>> {
+    let syn::Expr::Array(array) = expr else {
+        return Err(syn::Error::new_spanned(
+            expr,
+            "`groups` must be an array of group names, e.g. `groups = [create]`",
+        ));
+    };
+
+    array
+        .elems
+        .iter()
+        .map(|elem| {
+            let syn::Expr::Path(p) = elem else {
+                return Err(syn::Error::new_spanned(elem, "expected a group name"));
+            };
+            let Some(ident) = p.path.get_ident() else {
+                return Err(syn::Error::new_spanned(elem, "expected a group name"));
+            };
+
+            Ok(match ident.to_string().as_str() {
+                "default" => quote! { validrs::validate::Group::Default },
+                "create" => quote! { validrs::validate::Group::Create },
+                "update" => quote! { validrs::validate::Group::Update },
+                name => quote! { validrs::validate::Group::Custom(#name) },
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,77 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field::Field;
+use crate::utils::{arg_key, lit_str, unknown};
+
+#[derive(Default)]
+pub struct Color {
+    format: Option<syn::Ident>,
+    msg: Option<String>,
+}
+
+impl Color {
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
+
+        let format = match &self.format {
+            Some(v) => quote! { Some(validrs::rules::color::ColorFormat::#v) },
+            None => quote! { None },
+        };
+
+        let msg = match &self.msg {
+            Some(v) => quote! { Some(#v.to_string()) },
+            None => quote! { None },
+        };
+
+        Some(quote! {
+            {
+                use validrs::rules::color::ValidateColor as _;
+                #field_access.validate_color(#format, #msg)
+            }
+        })
+    }
+
+    pub fn parse(call: &syn::ExprCall, _field: &Field) -> syn::Result<Self> {
+        let mut format = None;
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            match arg {
+                syn::Expr::Path(p) if format.is_some() => {
+                    return Err(syn::Error::new_spanned(p, "color() takes one format"));
+                }
+                syn::Expr::Path(p) if p.path.get_ident().is_some() => {
+                    let ident = p.path.get_ident().unwrap();
+                    let variant = match ident.to_string().as_str() {
+                        "hex" => "Hex",
+                        "rgb" => "Rgb",
+                        "rgba" => "Rgba",
+                        "hsl" => "Hsl",
+                        "hsla" => "Hsla",
+                        name => {
+                            return Err(unknown(
+                                "color format",
+                                p,
+                                name,
+                                &["hex", "rgb", "rgba", "hsl", "hsla"],
+                            ));
+                        }
+                    };
+                    format = Some(syn::Ident::new(variant, ident.span()));
+                }
+                syn::Expr::Assign(assign) => match arg_key(assign)?.as_str() {
+                    "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                    key => return Err(unknown("argument", &assign.left, key, &["msg"])),
+                },
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        "color() expects a color format or a `msg` argument",
+                    ));
+                }
+            }
+        }
+        Ok(Self { format, msg })
+    }
+}
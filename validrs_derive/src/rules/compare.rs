@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field::{Field, Scope};
+use crate::utils::{apply_template, arg_key, lit_str, unknown};
+
+pub enum CompareKind {
+    MustMatch,
+    GreaterThan,
+    LessThan,
+}
+
+/// Compares the field with another field of the struct, written either as
+/// `gt_field = "other"` or `gt_field(other = "other", msg = "...")`.
+pub struct Compare {
+    kind: CompareKind,
+    other: Field,
+    msg: Option<String>,
+}
+
+impl Compare {
+    pub fn other(&self) -> &Field {
+        &self.other
+    }
+
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
+
+        let other = &self.other.access;
+        let other_name = &self.other.name;
+
+        let method = match self.kind {
+            CompareKind::MustMatch => quote! { validate_must_match },
+            CompareKind::GreaterThan => quote! { validate_gt_field },
+            CompareKind::LessThan => quote! { validate_lt_field },
+        };
+
+        let msg = match &self.msg {
+            Some(v) => quote! { Some(#v.to_string()) },
+            None => quote! { None },
+        };
+
+        Some(quote! {
+            {
+                use validrs::rules::compare::ValidateCompare as _;
+                #field_access.#method(&#other, #other_name, #msg)
+            }
+        })
+    }
+
+    pub fn parse(
+        kind: CompareKind,
+        expr: &syn::Expr,
+        field: &Field,
+        scope: &Scope,
+    ) -> syn::Result<Self> {
+        let mut other = None;
+        let mut msg = None;
+
+        let args: Vec<&syn::Expr> = match expr {
+            syn::Expr::Assign(assign) => {
+                other = Some(parse_other(&assign.right, scope)?);
+                Vec::new()
+            }
+            syn::Expr::Call(call) => call.args.iter().collect(),
+            _ => Vec::new(),
+        };
+
+        for arg in args {
+            let syn::Expr::Assign(assign) = arg else {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "expected `other` or `msg` arguments",
+                ));
+            };
+
+            match arg_key(assign)?.as_str() {
+                "other" => other = Some(parse_other(&assign.right, scope)?),
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                key => return Err(unknown("argument", &assign.left, key, &["other", "msg"])),
+            }
+        }
+        let Some(other) = other else {
+            return Err(syn::Error::new_spanned(
+                expr,
+                "field comparison requires the name of the `other` field",
+            ));
+        };
+
+        let msg = match msg {
+            Some(msg) => Some(apply_template(
+                &msg,
+                &[("field", Some(&field.name)), ("other", Some(&other.name))],
+                field,
+            )?),
+            None => None,
+        };
+
+        Ok(Self { kind, other, msg })
+    }
+}
+
+fn parse_other(expr: &syn::Expr, scope: &Scope) -> syn::Result<Field> {
+    let lit = lit_str(expr, "other")?;
+
+    match scope.get(&lit.value()) {
+        Some(other) => Ok(other.clone()),
+        None => Err(unknown("field", &lit, &lit.value(), &scope.names())),
+    }
+}
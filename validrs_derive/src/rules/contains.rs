@@ -1,14 +1,17 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::field::Field;
+use crate::utils::{arg_key, lit_str, unknown};
+
 pub struct Contains {
     values: Vec<String>,
     msg: Option<String>,
 }
 
 impl Contains {
-    pub fn check(&self, field_name: &syn::Ident) -> Option<TokenStream> {
-        let field_access = quote! { self.#field_name };
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
 
         let values = &self.values;
         let msg = match &self.msg {
@@ -16,39 +19,47 @@ impl Contains {
             None => quote! { None },
         };
 
-        Some(quote! { #field_access.validate_contains(&[#(#values),*], #msg)?; })
+        Some(quote! {
+            {
+                use validrs::rules::contains::ValidateContains as _;
+                #field_access.validate_contains(&[#(#values),*], #msg)
+            }
+        })
     }
 
-    pub fn parse(call: &syn::ExprCall, field_name: &syn::Ident) -> syn::Result<Self> {
+    pub fn parse(call: &syn::ExprCall, _field: &Field) -> syn::Result<Self> {
         let mut values = Vec::new();
         let mut msg = None;
 
-        for expr in call.args.iter() {
-            match expr {
-                syn::Expr::Array(syn::ExprArray { elems, .. }) => {
-                    for elem in elems {
-                        if let syn::Expr::Lit(lit) = elem {
-                            if let syn::Lit::Str(lit_str) = &lit.lit {
-                                values.push(lit_str.value());
-                            }
-                        }
+        for arg in call.args.iter() {
+            match arg {
+                syn::Expr::Array(array) => {
+                    for elem in &array.elems {
+                        let syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        }) = elem
+                        else {
+                            return Err(syn::Error::new_spanned(
+                                elem,
+                                "contains() values must be string literals",
+                            ));
+                        };
+                        values.push(lit.value());
                     }
                 }
-                syn::Expr::Assign(assign) => {
-                    if let syn::Expr::Path(p) = &*assign.left {
-                        if p.path.segments.last().unwrap().ident == "msg" {
-                            if let syn::Expr::Lit(lit) = &*assign.right {
-                                if let syn::Lit::Str(lit_str) = &lit.lit {
-                                    msg = Some(lit_str.value());
-                                }
-                            }
-                        }
-                    }
+                syn::Expr::Assign(assign) => match arg_key(assign)?.as_str() {
+                    "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                    key => return Err(unknown("argument", &assign.left, key, &["msg"])),
+                },
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        "contains() expects an array of string literals, e.g. `contains([\"@\"])`",
+                    ));
                 }
-                _ => continue,
             }
         }
-
         if values.is_empty() {
             return Err(syn::Error::new_spanned(
                 call,
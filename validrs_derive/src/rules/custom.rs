@@ -0,0 +1,82 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field::Field;
+use crate::utils::{arg_key, lit_str, unknown};
+
+/// Calls a user function `fn(&FieldType, args...) -> validrs::error::Result<()>`, or
+/// an `async fn` of the same shape for `async_custom`.
+pub struct Custom {
+    function: syn::Expr,
+    asynchronous: bool,
+    args: Vec<syn::Expr>,
+    msg: Option<String>,
+}
+
+impl Custom {
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
+
+        let function = &self.function;
+        let args = &self.args;
+
+        let call = match self.asynchronous {
+            true => quote! { #function(&#field_access #(, #args)*).await },
+            false => quote! { #function(&#field_access #(, #args)*) },
+        };
+
+        Some(match &self.msg {
+            Some(msg) => quote! {
+                validrs::error::Result::<()>::map_err(#call, |err| {
+                    err.with_message(Some(#msg.to_string()))
+                })
+            },
+            None => call,
+        })
+    }
+
+    pub fn parse(call: &syn::ExprCall, asynchronous: bool) -> syn::Result<Self> {
+        let mut function = None;
+        let mut args = Vec::new();
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            let syn::Expr::Assign(assign) = arg else {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "expected `function`, `args` or `msg` arguments",
+                ));
+            };
+
+            match arg_key(assign)?.as_str() {
+                "function" => function = Some((*assign.right).clone()),
+                "args" => match &*assign.right {
+                    syn::Expr::Tuple(tuple) => args = tuple.elems.iter().cloned().collect(),
+                    expr => args = vec![expr.clone()],
+                },
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                key => {
+                    return Err(unknown(
+                        "argument",
+                        &assign.left,
+                        key,
+                        &["function", "args", "msg"],
+                    ));
+                }
+            }
+        }
+        let Some(function) = function else {
+            return Err(syn::Error::new_spanned(
+                call,
+                "custom() validator requires `function`",
+            ));
+        };
+
+        Ok(Self {
+            function,
+            asynchronous,
+            args,
+            msg,
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field::Field;
+use crate::utils::{arg_key, lit_str, unknown};
+
+#[derive(Default)]
+pub struct Ip {
+    version: Option<syn::Ident>,
+    msg: Option<String>,
+}
+
+impl Ip {
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
+
+        let version = match &self.version {
+            Some(v) => quote! { Some(validrs::rules::ip::IpVersions::#v) },
+            None => quote! { None },
+        };
+
+        let msg = match &self.msg {
+            Some(v) => quote! { Some(#v.to_string()) },
+            None => quote! { None },
+        };
+
+        Some(quote! {
+            {
+                use validrs::rules::ip::ValidateIp as _;
+                #field_access.validate_ip(#version, #msg)
+            }
+        })
+    }
+
+    pub fn parse(call: &syn::ExprCall, _field: &Field) -> syn::Result<Self> {
+        let mut version = None;
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            match arg {
+                syn::Expr::Path(p) if version.is_some() => {
+                    return Err(syn::Error::new_spanned(p, "ip() takes one version"));
+                }
+                syn::Expr::Path(p) if p.path.get_ident().is_some() => {
+                    let ident = p.path.get_ident().unwrap();
+                    version = match ident.to_string().as_str() {
+                        "v4" => Some(syn::Ident::new("V4", ident.span())),
+                        "v6" => Some(syn::Ident::new("V6", ident.span())),
+                        name => return Err(unknown("ip version", p, name, &["v4", "v6"])),
+                    };
+                }
+                syn::Expr::Assign(assign) => match arg_key(assign)?.as_str() {
+                    "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                    key => return Err(unknown("argument", &assign.left, key, &["msg"])),
+                },
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        "ip() expects `v4`, `v6` or a `msg` argument",
+                    ));
+                }
+            }
+        }
+        Ok(Self { version, msg })
+    }
+}
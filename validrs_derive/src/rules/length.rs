@@ -1,83 +1,73 @@
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::spanned::Spanned;
 
-use crate::utils::apply_template;
+use crate::field::Field;
+use crate::rules::limits::{Limit, Limits};
+use crate::utils::unknown;
+
+const UNITS: [(&str, &str); 4] = [
+    ("bytes", "Bytes"),
+    ("chars", "Chars"),
+    ("graphemes", "Graphemes"),
+    ("utf16", "Utf16"),
+];
 
 pub struct Length {
-    min: Option<usize>,
-    max: Option<usize>,
-    msg: Option<String>,
+    limits: Limits,
+    /// Variant of `LengthUnit`, `chars` unless set with `unit = ...`.
+    unit: syn::Ident,
 }
 
 impl Length {
-    pub fn check(&self, field_name: &syn::Ident) -> Option<TokenStream> {
-        let field_access = quote! { self.#field_name };
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
 
-        let min = match &self.min {
-            Some(v) => quote! { Some(#v) },
-            None => quote! { None },
-        };
+        let min = Limit::tokens(&self.limits.min);
+        let max = Limit::tokens(&self.limits.max);
+        let unit = &self.unit;
 
-        let max = match &self.max {
+        let msg = match &self.limits.msg {
             Some(v) => quote! { Some(#v) },
             None => quote! { None },
         };
 
-        let msg = match &self.msg {
-            Some(v) => quote! { Some(#v.to_string()) },
-            None => quote! { None },
-        };
-
-        Some(quote! { #field_access.validate_length(#min, #max, #msg)?; })
+        Some(quote! {
+            {
+                use validrs::rules::length::ValidateLength as _;
+                #field_access.validate_length(
+                    #min,
+                    #max,
+                    validrs::rules::length::LengthUnit::#unit,
+                    #msg,
+                )
+            }
+        })
     }
 
-    pub fn parse(call: &syn::ExprCall, field_name: &syn::Ident) -> syn::Result<Self> {
-        let mut min = None;
-        let mut max = None;
-        let mut msg = None;
+    pub fn parse(call: &syn::ExprCall, field: &Field) -> syn::Result<Self> {
+        let (limits, extras) = Limits::parse(call, field, "len", &["unit"])?;
+        let mut unit = syn::Ident::new("Chars", call.span());
 
-        for arg in call.args.iter() {
-            if let syn::Expr::Assign(assign) = arg {
-                let ident = if let syn::Expr::Path(p) = &*assign.left {
-                    p.path.segments.last().unwrap().ident.to_string()
-                } else {
-                    continue;
-                };
-
-                match ident.as_str() {
-                    "min" | "max" => {
-                        if let syn::Expr::Lit(syn::ExprLit {
-                            lit: syn::Lit::Int(lit),
-                            ..
-                        }) = &*assign.right.clone()
-                        {
-                            let value = lit.base10_parse::<usize>().ok();
-                            if ident == "min" {
-                                min = value;
-                            } else {
-                                max = value;
-                            }
-                        }
-                    }
-                    "msg" => {
-                        if let syn::Expr::Lit(syn::ExprLit {
-                            lit: syn::Lit::Str(lit),
-                            ..
-                        }) = &*assign.right.clone()
-                        {
-                            let result = apply_template(
-                                &lit.value(),
-                                &[("min", min.as_ref()), ("max", max.as_ref())],
-                                field_name,
-                            )?;
-                            msg = Some(result);
-                        }
-                    }
-                    _ => continue,
-                }
-            }
+        for assign in extras {
+            let syn::Expr::Path(path) = &*assign.right else {
+                return Err(syn::Error::new_spanned(
+                    &assign.right,
+                    "`unit` expects one of `bytes`, `chars`, `graphemes` or `utf16`",
+                ));
+            };
+            let name = path
+                .path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .unwrap_or_default();
+            let Some((_, variant)) = UNITS.iter().find(|(key, _)| *key == name) else {
+                let names = UNITS.map(|(key, _)| key);
+                return Err(unknown("unit", path, &name, &names));
+            };
+            unit = syn::Ident::new(variant, path.span());
         }
 
-        Ok(Self { min, max, msg })
+        Ok(Self { limits, unit })
     }
 }
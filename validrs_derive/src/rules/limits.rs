@@ -0,0 +1,230 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field::Field;
+use crate::utils::{apply_template, arg_key, check_bounds, lit_str, unknown};
+
+/// A `min` or `max` bound: a literal, a constant or any expression of the rule's
+/// bound type, e.g. `len(max = limits::NAME_LEN)` or `rng(min = -10)`.
+pub struct Limit {
+    expr: syn::Expr,
+    /// Value of a numeric literal, known during expansion.
+    literal: Option<(f64, String)>,
+    kind: Kind,
+}
+
+/// `min`/`max` and closed range ends are inclusive, `exclusive_min`/`exclusive_max`
+/// and the end of a half-open range are not.
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Inclusive,
+    Exclusive,
+    Equal,
+}
+
+/// The bounds and message shared by `len(...)` and `rng(...)`. An `equal` bound is
+/// kept as the `min`.
+pub struct Limits {
+    pub min: Option<Limit>,
+    pub max: Option<Limit>,
+    pub msg: Option<TokenStream>,
+}
+
+impl Limit {
+    fn parse(expr: &syn::Expr, kind: Kind) -> Self {
+        Self {
+            expr: expr.clone(),
+            literal: literal(expr),
+            kind,
+        }
+    }
+
+    pub fn tokens(limit: &Option<Limit>) -> TokenStream {
+        let Some(limit) = limit else {
+            return quote! { None };
+        };
+
+        let expr = &limit.expr;
+        let variant = match limit.kind {
+            Kind::Inclusive => quote! { Inclusive },
+            Kind::Exclusive => quote! { Exclusive },
+            Kind::Equal => quote! { Equal },
+        };
+        quote! { Some(validrs::rules::bound::Bound::#variant(#expr)) }
+    }
+
+    fn value(&self) -> Option<f64> {
+        self.literal.as_ref().map(|(value, _)| *value)
+    }
+}
+
+impl Limits {
+    /// Arguments named in `extra` are left to the rule and returned.
+    pub fn parse<'a>(
+        call: &'a syn::ExprCall,
+        field: &Field,
+        rule: &str,
+        extra: &[&str],
+    ) -> syn::Result<(Self, Vec<&'a syn::ExprAssign>)> {
+        let mut min: Option<Limit> = None;
+        let mut max: Option<Limit> = None;
+        let mut msg = None;
+        let mut range = false;
+        let mut extras = Vec::new();
+
+        let conflict = |tokens: &dyn quote::ToTokens| {
+            syn::Error::new_spanned(
+                tokens,
+                format!("{rule}() takes one range, one `equal` or one lower and one upper bound"),
+            )
+        };
+
+        for arg in call.args.iter() {
+            let assign = match arg {
+                syn::Expr::Assign(assign) => assign,
+                syn::Expr::Range(expr) => {
+                    if range || min.is_some() || max.is_some() {
+                        return Err(conflict(expr));
+                    }
+                    range = true;
+                    min = expr
+                        .start
+                        .as_deref()
+                        .map(|start| Limit::parse(start, Kind::Inclusive));
+                    max = expr.end.as_deref().map(|end| match expr.limits {
+                        syn::RangeLimits::HalfOpen(_) => Limit::parse(end, Kind::Exclusive),
+                        syn::RangeLimits::Closed(_) => Limit::parse(end, Kind::Inclusive),
+                    });
+                    continue;
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        format!("{rule}() expects a range or `key = value` arguments"),
+                    ));
+                }
+            };
+
+            let key = arg_key(assign)?;
+            let equal = min.as_ref().is_some_and(|min| min.kind == Kind::Equal);
+            match key.as_str() {
+                "min" | "exclusive_min" if range || min.is_some() => return Err(conflict(assign)),
+                "max" | "exclusive_max" if range || equal || max.is_some() => {
+                    return Err(conflict(assign));
+                }
+                "equal" if range || min.is_some() || max.is_some() => {
+                    return Err(conflict(assign));
+                }
+                "min" => min = Some(Limit::parse(&assign.right, Kind::Inclusive)),
+                "exclusive_min" => min = Some(Limit::parse(&assign.right, Kind::Exclusive)),
+                "max" => max = Some(Limit::parse(&assign.right, Kind::Inclusive)),
+                "exclusive_max" => max = Some(Limit::parse(&assign.right, Kind::Exclusive)),
+                "equal" => min = Some(Limit::parse(&assign.right, Kind::Equal)),
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?),
+                key if extra.contains(&key) => extras.push(assign),
+                key => {
+                    let mut known = vec!["min", "max", "exclusive_min", "exclusive_max", "equal"];
+                    known.extend(extra);
+                    known.push("msg");
+                    return Err(unknown("argument", &assign.left, key, &known));
+                }
+            }
+        }
+
+        if min.is_none() && max.is_none() {
+            return Err(syn::Error::new_spanned(
+                call,
+                format!("{rule}() requires a bound: a range, `min`, `max` or `equal`"),
+            ));
+        }
+
+        check_bounds(
+            min.as_ref().and_then(Limit::value),
+            max.as_ref().and_then(Limit::value),
+            [&min, &max].iter().any(|limit| {
+                limit
+                    .as_ref()
+                    .is_some_and(|limit| limit.kind == Kind::Exclusive)
+            }),
+            call,
+        )?;
+
+        let mut limits = Self {
+            min,
+            max,
+            msg: None,
+        };
+        limits.msg = match msg {
+            Some(msg) => Some(limits.message(&msg, field)?),
+            None => None,
+        };
+
+        Ok((limits, extras))
+    }
+
+    /// `{{min}}`, `{{max}}` and `{{equal}}` of literal bounds are filled in during
+    /// expansion, the ones of constants and expressions when the rule fails. Both
+    /// render the way `Display` renders the value, `0.0` as `0`.
+    fn message(&self, msg: &syn::LitStr, field: &Field) -> syn::Result<TokenStream> {
+        let (min, equal) = match &self.min {
+            Some(limit) if limit.kind == Kind::Equal => (&None, &self.min),
+            _ => (&self.min, &None),
+        };
+        let limits = [("min", min), ("max", &self.max), ("equal", equal)];
+
+        let values: Vec<(&str, Option<String>)> = limits
+            .iter()
+            .map(|(key, limit)| {
+                let value = limit.as_ref().map(|limit| match &limit.literal {
+                    Some((_, text)) => text.clone(),
+                    None => format!("{{{{{key}}}}}"),
+                });
+                (*key, value)
+            })
+            .collect();
+        let values: Vec<(&str, Option<&String>)> = values
+            .iter()
+            .map(|(key, value)| (*key, value.as_ref()))
+            .collect();
+        let template = apply_template(&msg.value(), &values, field)?;
+
+        let replacements = limits.iter().filter_map(|(key, limit)| {
+            let limit = limit.as_ref().filter(|limit| limit.literal.is_none())?;
+            let placeholder = format!("{{{{{key}}}}}");
+            let expr = &limit.expr;
+            template
+                .contains(&placeholder)
+                .then(|| quote! { .replace(#placeholder, &(#expr).to_string()) })
+        });
+
+        Ok(quote! { #template.to_string() #(#replacements)* })
+    }
+}
+
+/// Value and `Display` text of an integer or float literal, possibly negated.
+fn literal(expr: &syn::Expr) -> Option<(f64, String)> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => {
+            let value: u128 = lit.base10_parse().ok()?;
+            Some((value as f64, value.to_string()))
+        }
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(lit),
+            ..
+        }) => {
+            let value: f64 = lit.base10_parse().ok()?;
+            Some((value, value.to_string()))
+        }
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal(expr).map(|(value, text)| (-value, format!("-{text}"))),
+        syn::Expr::Group(group) => literal(&group.expr),
+        syn::Expr::Paren(paren) => literal(&paren.expr),
+        _ => None,
+    }
+}
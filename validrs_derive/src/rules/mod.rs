@@ -1,5 +1,15 @@
+pub mod color;
+pub mod compare;
 pub mod contains;
+pub mod custom;
+pub mod ip;
 pub mod length;
+pub mod limits;
+pub mod nested;
+pub mod presence;
 pub mod range;
 pub mod regex;
+pub mod reject;
 pub mod required;
+pub mod schema;
+pub mod simple;
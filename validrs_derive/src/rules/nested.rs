@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::container::Container;
+use crate::field::Field;
+
+/// Validates a field whose type also implements `Validate` and reports its errors
+/// under the field name.
+#[derive(Default)]
+pub struct Nested;
+
+impl Nested {
+    pub fn check(&self, field: &Field, container: &Container) -> TokenStream {
+        let field_access = &field.access;
+        let path = &field.path;
+
+        let validate = match &container.context {
+            Some(context) => quote! {
+                validrs::validate::ValidateWithContext::<#context>::validate_group_with_context(&#field_access, ctx, group)
+            },
+            None => quote! { validrs::validate::Validate::validate_group(&#field_access, group) },
+        };
+
+        quote! {
+            if let Err(inner) = #validate {
+                errors.merge(#path, inner);
+            }
+        }
+    }
+
+    /// Runs only the async rules of the nested value, its sync rules are covered by
+    /// [`Nested::check`]. The future is boxed so recursive types such as a tree of
+    /// `Vec<Self>` have a finite size.
+    pub fn check_async(&self, field: &Field, container: &Container) -> TokenStream {
+        let field_access = &field.access;
+        let path = &field.path;
+
+        let context = match &container.context {
+            Some(context) => quote! { #context },
+            None => quote! { __C },
+        };
+
+        quote! {
+            if let Err(inner) = ::std::boxed::Box::pin(validrs::validate::AsyncValidate::<#context>::validate_async_rules(&#field_access, ctx, group)).await {
+                errors.merge(#path, inner);
+            }
+        }
+    }
+}
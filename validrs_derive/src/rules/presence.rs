@@ -0,0 +1,72 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::ext::IdentExt as _;
+
+use crate::field::{Field, Scope};
+use crate::utils::{arg_key, lit_str, unknown};
+
+/// Struct-level field group such as `one_of_required(email, phone)`.
+pub struct Presence {
+    kind: syn::Ident,
+    fields: Vec<Field>,
+    msg: Option<String>,
+}
+
+impl Presence {
+    pub fn check(&self) -> TokenStream {
+        let kind = &self.kind;
+        let names = self.fields.iter().map(|field| &field.name);
+        let accesses = self.fields.iter().map(|field| &field.access);
+        let paths = self.fields.iter().map(|field| &field.path);
+
+        let msg = match &self.msg {
+            Some(v) => quote! { Some(#v.to_string()) },
+            None => quote! { None },
+        };
+
+        quote! {
+            if let Err(err) = validrs::rules::presence::validate_presence(
+                validrs::rules::presence::Presence::#kind,
+                &[#((#names, &#accesses as &dyn validrs::rules::required::ValidateRequired)),*],
+                #msg,
+            ) {
+                #(errors.add(#paths, err.clone());)*
+            }
+        }
+    }
+
+    pub fn parse(kind: &str, call: &syn::ExprCall, scope: &Scope) -> syn::Result<Self> {
+        let kind = syn::Ident::new(kind, Span::call_site());
+        let mut fields = Vec::new();
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            match arg {
+                syn::Expr::Path(p) => {
+                    let Some(ident) = p.path.get_ident() else {
+                        return Err(syn::Error::new_spanned(p, "expected a field name"));
+                    };
+
+                    let name = ident.unraw().to_string();
+                    let Some(field) = scope.get(&name) else {
+                        return Err(unknown("field", ident, &name, &scope.names()));
+                    };
+                    fields.push(field.clone());
+                }
+                syn::Expr::Assign(assign) => match arg_key(assign)?.as_str() {
+                    "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                    key => return Err(unknown("argument", &assign.left, key, &["msg"])),
+                },
+                _ => return Err(syn::Error::new_spanned(arg, "expected a field name")),
+            }
+        }
+        if fields.len() < 2 {
+            return Err(syn::Error::new_spanned(
+                call,
+                "field group requires at least two fields",
+            ));
+        }
+
+        Ok(Self { kind, fields, msg })
+    }
+}
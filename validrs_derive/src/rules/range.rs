@@ -1,83 +1,35 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::utils::apply_template;
+use crate::field::Field;
+use crate::rules::limits::{Limit, Limits};
 
 pub struct Range {
-    min: Option<usize>,
-    max: Option<usize>,
-    msg: Option<String>,
+    limits: Limits,
 }
 
 impl Range {
-    pub fn check(&self, field_name: &syn::Ident) -> Option<TokenStream> {
-        let field_access = quote! { self.#field_name };
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
 
-        let min = match &self.min {
-            Some(v) => quote! { Some(#v) },
-            None => quote! { None },
-        };
+        let min = Limit::tokens(&self.limits.min);
+        let max = Limit::tokens(&self.limits.max);
 
-        let max = match &self.max {
+        let msg = match &self.limits.msg {
             Some(v) => quote! { Some(#v) },
             None => quote! { None },
         };
 
-        let msg = match &self.msg {
-            Some(v) => quote! { Some(#v.to_string()) },
-            None => quote! { None },
-        };
-
-        Some(quote! { #field_access.validate_range(#min, #max, #msg)?; })
-    }
-
-    pub fn parse(call: &syn::ExprCall, field_name: &syn::Ident) -> syn::Result<Self> {
-        let mut min = None;
-        let mut max = None;
-        let mut msg = None;
-
-        for arg in call.args.iter() {
-            if let syn::Expr::Assign(assign) = arg {
-                let ident = if let syn::Expr::Path(p) = &*assign.left {
-                    p.path.segments.last().unwrap().ident.to_string()
-                } else {
-                    continue;
-                };
-
-                match ident.as_str() {
-                    "min" | "max" => {
-                        if let syn::Expr::Lit(syn::ExprLit {
-                            lit: syn::Lit::Int(lit),
-                            ..
-                        }) = &*assign.right.clone()
-                        {
-                            let value = lit.base10_parse::<usize>().ok();
-                            if ident == "min" {
-                                min = value;
-                            } else {
-                                max = value;
-                            }
-                        }
-                    }
-                    "msg" => {
-                        if let syn::Expr::Lit(syn::ExprLit {
-                            lit: syn::Lit::Str(lit),
-                            ..
-                        }) = &*assign.right.clone()
-                        {
-                            let result = apply_template(
-                                &lit.value(),
-                                &[("min", min.as_ref()), ("max", max.as_ref())],
-                                field_name,
-                            )?;
-                            msg = Some(result);
-                        }
-                    }
-                    _ => continue,
-                }
+        Some(quote! {
+            {
+                use validrs::rules::range::ValidateRange as _;
+                #field_access.validate_range(#min, #max, #msg)
             }
-        }
+        })
+    }
 
-        return Ok(Self { min, max, msg });
+    pub fn parse(call: &syn::ExprCall, field: &Field) -> syn::Result<Self> {
+        let (limits, _) = Limits::parse(call, field, "rng", &[])?;
+        Ok(Self { limits })
     }
 }
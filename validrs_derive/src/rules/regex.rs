@@ -1,3 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field::Field;
+use crate::utils::{arg_key, lit_str, unknown};
+
 pub struct Regex {
-    pattern: String,
+    source: RegexSource,
+    msg: Option<String>,
+}
+
+enum RegexSource {
+    /// Literal pattern, checked during expansion and compiled once into a static.
+    Pattern(syn::LitStr),
+    /// Path to an existing `LazyLock<Regex>` or `Regex` static.
+    Path(syn::Expr),
+}
+
+impl Regex {
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
+
+        let msg = match &self.msg {
+            Some(v) => quote! { Some(#v.to_string()) },
+            None => quote! { None },
+        };
+
+        let regex = match &self.source {
+            RegexSource::Pattern(pattern) => quote! {
+                static REGEX: ::std::sync::LazyLock<validrs::rules::regex::Regex> =
+                    ::std::sync::LazyLock::new(|| validrs::rules::regex::Regex::new(#pattern).unwrap());
+                let regex: &validrs::rules::regex::Regex = &REGEX;
+            },
+            RegexSource::Path(path) => quote! {
+                let regex: &validrs::rules::regex::Regex = &#path;
+            },
+        };
+
+        Some(quote! {
+            {
+                #regex
+                use validrs::rules::regex::ValidateRegex as _;
+                #field_access.validate_regex(regex, #msg)
+            }
+        })
+    }
+
+    pub fn parse(call: &syn::ExprCall, _field: &Field) -> syn::Result<Self> {
+        let mut source = None;
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            let syn::Expr::Assign(assign) = arg else {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "regex() expects `pattern`, `path` or `msg` arguments",
+                ));
+            };
+
+            match arg_key(assign)?.as_str() {
+                "pattern" | "path" if source.is_some() => {
+                    return Err(syn::Error::new_spanned(
+                        assign,
+                        "regex() takes either `pattern` or `path`, not both",
+                    ));
+                }
+                "pattern" => {
+                    let lit = lit_str(&assign.right, "pattern")?;
+                    if let Err(err) = ::regex::Regex::new(&lit.value()) {
+                        return Err(syn::Error::new_spanned(
+                            &lit,
+                            format!("Invalid regex pattern: {err}"),
+                        ));
+                    }
+                    source = Some(RegexSource::Pattern(lit));
+                }
+                "path" => source = Some(RegexSource::Path((*assign.right).clone())),
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                key => {
+                    return Err(unknown(
+                        "argument",
+                        &assign.left,
+                        key,
+                        &["pattern", "path", "msg"],
+                    ));
+                }
+            }
+        }
+        let Some(source) = source else {
+            return Err(syn::Error::new_spanned(
+                call,
+                "regex() validator requires either `pattern` or `path`",
+            ));
+        };
+
+        Ok(Self { source, msg })
+    }
 }
@@ -0,0 +1,50 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt as _;
+
+use crate::utils::{arg_key, lit_str, unknown};
+
+/// Variant-level rule rejecting the variant outright, e.g. a deprecated one.
+#[derive(Default)]
+pub struct Reject {
+    msg: Option<String>,
+}
+
+impl Reject {
+    pub fn check(&self, variant: &syn::Ident) -> TokenStream {
+        let variant = variant.unraw().to_string();
+
+        let msg = match &self.msg {
+            Some(msg) => quote! { Some(#msg.to_string()) },
+            None => quote! { None },
+        };
+        let err = quote! {
+            validrs::error::Error::RejectedVariant { variant: #variant.to_string() }
+                .with_message(#msg)
+        };
+
+        quote! {
+            errors.add(validrs::path::Path::field(#variant), #err);
+        }
+    }
+
+    pub fn parse(call: &syn::ExprCall) -> syn::Result<Self> {
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            let syn::Expr::Assign(assign) = arg else {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "reject() expects a `msg` argument",
+                ));
+            };
+
+            match arg_key(assign)?.as_str() {
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                key => return Err(unknown("argument", &assign.left, key, &["msg"])),
+            }
+        }
+
+        Ok(Self { msg })
+    }
+}
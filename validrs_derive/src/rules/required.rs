@@ -1,39 +1,129 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::field::{Field, Scope};
+use crate::utils::{arg_key, lit_str, unknown};
+
 #[derive(Default)]
 pub struct Required {
     msg: Option<String>,
 }
 
 impl Required {
-    pub fn check(&self, field_name: &syn::Ident) -> Option<TokenStream> {
-        let field_access = quote! { self.#field_name };
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
         let msg = match &self.msg {
             Some(v) => quote! { Some(#v.to_string()) },
             None => quote! { None },
         };
 
-        Some(quote! { #field_access.validate_required(#msg)?; })
+        Some(quote! {
+            {
+                use validrs::rules::required::ValidateRequired as _;
+                #field_access.validate_required(#msg)
+            }
+        })
     }
 
-    pub fn parse(call: &syn::ExprCall, field_name: &syn::Ident) -> syn::Result<Self> {
+    pub fn parse(call: &syn::ExprCall, _field: &Field) -> syn::Result<Self> {
         let mut msg = None;
 
-        for expr in call.args.iter() {
-            if let syn::Expr::Assign(assign) = expr {
-                if let syn::Expr::Path(p) = &*assign.left {
-                    if p.path.segments.last().unwrap().ident == "msg" {
-                        if let syn::Expr::Lit(lit) = &*assign.right {
-                            if let syn::Lit::Str(lit_str) = &lit.lit {
-                                msg = Some(lit_str.value());
-                            }
-                        }
-                    }
-                }
+        for arg in call.args.iter() {
+            let syn::Expr::Assign(assign) = arg else {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "required() expects a `msg` argument",
+                ));
+            };
+
+            match arg_key(assign)?.as_str() {
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                key => return Err(unknown("argument", &assign.left, key, &["msg"])),
             }
         }
 
         Ok(Self { msg })
     }
 }
+
+/// `required_if(field = "other", eq = value)` and its negation `required_unless`.
+pub struct RequiredIf {
+    other: Field,
+    eq: syn::Expr,
+    unless: bool,
+    msg: Option<String>,
+}
+
+impl RequiredIf {
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
+
+        let other = &self.other.access;
+        let eq = &self.eq;
+        let condition = match self.unless {
+            true => quote! { #other != #eq },
+            false => quote! { #other == #eq },
+        };
+
+        let msg = match &self.msg {
+            Some(v) => quote! { Some(#v.to_string()) },
+            None => quote! { None },
+        };
+
+        Some(quote! {
+            if #condition {
+                use validrs::rules::required::ValidateRequired as _;
+                #field_access.validate_required(#msg)
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    pub fn parse(call: &syn::ExprCall, unless: bool, scope: &Scope) -> syn::Result<Self> {
+        let mut other = None;
+        let mut eq = None;
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            let syn::Expr::Assign(assign) = arg else {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "expected `field`, `eq` or `msg` arguments",
+                ));
+            };
+
+            match arg_key(assign)?.as_str() {
+                "field" => {
+                    let lit = lit_str(&assign.right, "field")?;
+                    let Some(field) = scope.get(&lit.value()) else {
+                        return Err(unknown("field", &lit, &lit.value(), &scope.names()));
+                    };
+                    other = Some(field.clone());
+                }
+                "eq" => eq = Some((*assign.right).clone()),
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                key => {
+                    return Err(unknown(
+                        "argument",
+                        &assign.left,
+                        key,
+                        &["field", "eq", "msg"],
+                    ));
+                }
+            }
+        }
+        match (other, eq) {
+            (Some(other), Some(eq)) => Ok(Self {
+                other,
+                eq,
+                unless,
+                msg,
+            }),
+            _ => Err(syn::Error::new_spanned(
+                call,
+                "conditional required validator requires `field` and `eq`",
+            )),
+        }
+    }
+}
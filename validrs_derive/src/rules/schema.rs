@@ -0,0 +1,99 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::field::{Field, Scope};
+use crate::utils::{arg_key, lit_str, unknown};
+
+/// Struct-level rule calling `fn(&Self) -> validrs::error::Result<()>` once every
+/// field rule has run.
+pub struct Schema {
+    function: syn::Expr,
+    fields: Vec<Field>,
+    msg: Option<String>,
+}
+
+impl Schema {
+    pub fn check(&self) -> TokenStream {
+        let function = &self.function;
+        let paths = self.fields.iter().map(|field| &field.path);
+
+        let err = match &self.msg {
+            Some(msg) => {
+                quote! { validrs::error::Error::from(err).with_message(Some(#msg.to_string())) }
+            }
+            None => quote! { validrs::error::Error::from(err) },
+        };
+
+        let report = match self.fields.is_empty() {
+            true => quote! { errors.add(validrs::path::Path::root(), err); },
+            false => quote! {
+                #(errors.add(#paths, err.clone());)*
+            },
+        };
+
+        quote! {
+            if let Err(err) = #function(self) {
+                let err = #err;
+                #report
+            }
+        }
+    }
+
+    pub fn parse(call: &syn::ExprCall, scope: &Scope) -> syn::Result<Self> {
+        let mut function = None;
+        let mut fields = Vec::new();
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            let syn::Expr::Assign(assign) = arg else {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    "schema() expects `function`, `fields` or `msg` arguments",
+                ));
+            };
+
+            match arg_key(assign)?.as_str() {
+                "function" => function = Some((*assign.right).clone()),
+                "fields" => {
+                    let syn::Expr::Array(array) = &*assign.right else {
+                        return Err(syn::Error::new_spanned(
+                            &assign.right,
+                            "schema() `fields` must be an array of field names",
+                        ));
+                    };
+
+                    for elem in &array.elems {
+                        let lit = lit_str(elem, "fields")?;
+                        match scope.get(&lit.value()) {
+                            Some(field) => fields.push(field.clone()),
+                            None => {
+                                return Err(unknown("field", &lit, &lit.value(), &scope.names()));
+                            }
+                        }
+                    }
+                }
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                key => {
+                    return Err(unknown(
+                        "argument",
+                        &assign.left,
+                        key,
+                        &["function", "fields", "msg"],
+                    ));
+                }
+            }
+        }
+        let Some(function) = function else {
+            return Err(syn::Error::new_spanned(
+                call,
+                "schema() validator requires `function`",
+            ));
+        };
+
+        Ok(Self {
+            function,
+            fields,
+            msg,
+        })
+    }
+}
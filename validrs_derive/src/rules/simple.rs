@@ -0,0 +1,133 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::field::Field;
+use crate::utils::{arg_key, lit_str, unknown};
+
+/// A rule whose only argument is an optional `msg`, e.g. `email` or `positive`.
+pub struct SimpleRule {
+    name: &'static str,
+    module: &'static str,
+    trait_name: &'static str,
+    method: &'static str,
+}
+
+const SIMPLE_RULES: &[SimpleRule] = &[
+    SimpleRule {
+        name: "email",
+        module: "email",
+        trait_name: "ValidateEmail",
+        method: "validate_email",
+    },
+    SimpleRule {
+        name: "phone",
+        module: "phone",
+        trait_name: "ValidatePhone",
+        method: "validate_phone",
+    },
+    SimpleRule {
+        name: "alphanumeric",
+        module: "alphanumeric",
+        trait_name: "ValidateAlphanumeric",
+        method: "validate_alphanumeric",
+    },
+    SimpleRule {
+        name: "alphabetic",
+        module: "alphapetic",
+        trait_name: "ValidateAlphabetic",
+        method: "validate_alphabetic",
+    },
+    SimpleRule {
+        name: "ascii",
+        module: "ascii",
+        trait_name: "ValidateAscii",
+        method: "validate_ascii",
+    },
+    SimpleRule {
+        name: "lowercase",
+        module: "lowercase",
+        trait_name: "ValidateLowercase",
+        method: "validate_lowercase",
+    },
+    SimpleRule {
+        name: "uppercase",
+        module: "uppercase",
+        trait_name: "ValidateUppercase",
+        method: "validate_uppercase",
+    },
+    SimpleRule {
+        name: "positive",
+        module: "positive",
+        trait_name: "ValidatePositive",
+        method: "validate_positive",
+    },
+    SimpleRule {
+        name: "negative",
+        module: "negative",
+        trait_name: "ValidateNegative",
+        method: "validate_negative",
+    },
+];
+
+pub struct Simple {
+    rule: &'static SimpleRule,
+    msg: Option<String>,
+}
+
+impl Simple {
+    pub fn find(name: &str) -> Option<&'static SimpleRule> {
+        SIMPLE_RULES.iter().find(|rule| rule.name == name)
+    }
+
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        SIMPLE_RULES.iter().map(|rule| rule.name)
+    }
+
+    pub fn new(rule: &'static SimpleRule) -> Self {
+        Self { rule, msg: None }
+    }
+
+    pub fn check(&self, field: &Field) -> Option<TokenStream> {
+        let field_access = &field.access;
+
+        let module = syn::Ident::new(self.rule.module, Span::call_site());
+        let trait_name = syn::Ident::new(self.rule.trait_name, Span::call_site());
+        let method = syn::Ident::new(self.rule.method, Span::call_site());
+
+        let msg = match &self.msg {
+            Some(v) => quote! { Some(#v.to_string()) },
+            None => quote! { None },
+        };
+
+        Some(quote! {
+            {
+                use validrs::rules::#module::#trait_name as _;
+                #field_access.#method(#msg)
+            }
+        })
+    }
+
+    pub fn parse(
+        rule: &'static SimpleRule,
+        call: &syn::ExprCall,
+        _field: &Field,
+    ) -> syn::Result<Self> {
+        let mut msg = None;
+
+        for arg in call.args.iter() {
+            let syn::Expr::Assign(assign) = arg else {
+                return Err(syn::Error::new_spanned(
+                    arg,
+                    format!("{}() expects a `msg` argument", rule.name),
+                ));
+            };
+
+            match arg_key(assign)?.as_str() {
+                "msg" => msg = Some(lit_str(&assign.right, "msg")?.value()),
+                key => return Err(unknown("argument", &assign.left, key, &["msg"])),
+            }
+        }
+
+        Ok(Self { rule, msg })
+    }
+}
@@ -1,24 +1,23 @@
 use std::fmt::Display;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
 use syn::spanned::Spanned;
 
-pub fn compile_error<S: Spanned, T: Display>(span: S, err: T) -> TokenStream {
-    syn::Error::new(span.span(), err).to_compile_error().into()
-}
+use crate::field::Field;
 
 pub fn apply_template<T: Display>(
     template: &str,
     values: &[(&str, Option<&T>)],
-    field_name: &syn::Ident,
+    field: &Field,
 ) -> syn::Result<String> {
     for (key, value) in values {
         if template.contains(&format!("{{{key}}}")) && value.is_none() {
             let error_msg = format!(
                 "The 'msg' for field `{}` contains '{{{{{}}}}}', but '{}' is not specified",
-                field_name, key, key
+                field.name, key, key
             );
-            return Err(syn::Error::new(field_name.span(), error_msg));
+            return Err(syn::Error::new(field.span, error_msg));
         }
     }
 
@@ -31,3 +30,153 @@ pub fn apply_template<T: Display>(
 
     Ok(result)
 }
+
+/// Name of a `key = value` rule argument, e.g. `min` in `len(min = 1)`.
+pub fn arg_key(assign: &syn::ExprAssign) -> syn::Result<String> {
+    match &*assign.left {
+        syn::Expr::Path(p) if p.path.get_ident().is_some() => {
+            Ok(p.path.get_ident().unwrap().to_string())
+        }
+        left => Err(syn::Error::new_spanned(left, "expected an argument name")),
+    }
+}
+
+/// Value of an argument that must be a string literal, e.g. `msg = "..."`.
+pub fn lit_str(expr: &syn::Expr, key: &str) -> syn::Result<syn::LitStr> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => Ok(lit.clone()),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            format!("`{key}` must be a string literal"),
+        )),
+    }
+}
+
+/// Rejects literal bounds that no value can satisfy, such as `len(min = 16, max = 1)`
+/// or `rng(1..1)`.
+pub fn check_bounds<T: PartialOrd + Display>(
+    min: Option<T>,
+    max: Option<T>,
+    exclusive: bool,
+    tokens: impl ToTokens,
+) -> syn::Result<()> {
+    match (min, max) {
+        (Some(min), Some(max)) if min > max => Err(syn::Error::new_spanned(
+            tokens,
+            format!("`min` ({min}) is greater than `max` ({max})"),
+        )),
+        (Some(min), Some(max)) if exclusive && min == max => Err(syn::Error::new_spanned(
+            tokens,
+            format!("`min` ({min}) is not less than the exclusive `max` ({max})"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Rejects a `key = value` argument given twice, e.g. `len(msg = "a", msg = "b")`.
+pub fn reject_duplicates(call: &syn::ExprCall) -> syn::Result<()> {
+    let mut seen = Vec::new();
+    for arg in call.args.iter() {
+        let syn::Expr::Assign(assign) = arg else {
+            continue;
+        };
+        let key = arg_key(assign)?;
+        if seen.contains(&key) {
+            return Err(syn::Error::new_spanned(
+                arg,
+                format!("duplicate `{key}` argument"),
+            ));
+        }
+        seen.push(key);
+    }
+    Ok(())
+}
+
+/// Error for an unknown rule, argument or field, suggesting the closest known name.
+pub fn unknown(kind: &str, tokens: impl ToTokens, name: &str, known: &[&str]) -> syn::Error {
+    let msg = match suggest(name, known) {
+        Some(candidate) => format!("Unknown {kind}: `{name}`, did you mean `{candidate}`?"),
+        None if known.is_empty() => format!("Unknown {kind}: `{name}`"),
+        None => format!(
+            "Unknown {kind}: `{name}`, expected one of {}",
+            known
+                .iter()
+                .map(|known| format!("`{known}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+
+    syn::Error::new_spanned(tokens, msg)
+}
+
+/// Closest known name within a third of the name's length, typos such as `mni` included.
+fn suggest<'a>(name: &str, known: &[&'a str]) -> Option<&'a str> {
+    let limit = (name.chars().count() / 3).max(1);
+
+    known
+        .iter()
+        .map(|candidate| (distance(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance counting an adjacent transposition as one edit.
+fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        rows[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            rows[i][j] = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                rows[i][j] = rows[i][j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+/// Moves the trait method calls of a rule onto the rule in the attribute, so a field
+/// type lacking the rule's trait is reported there and not inside the derive. Only the
+/// location moves, the method name stays macro-generated so the compiler does not
+/// suggest renaming the rule to a similar method.
+pub fn at_rule(check: TokenStream, rule: &syn::Expr) -> TokenStream {
+    respan(check, rule.span())
+}
+
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(mut ident) if ident.to_string().starts_with("validate") => {
+                ident.set_span(ident.span().located_at(span));
+                TokenTree::Ident(ident)
+            }
+            TokenTree::Group(group) => {
+                let mut respanned =
+                    proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(group.span());
+                TokenTree::Group(respanned)
+            }
+            token => token,
+        })
+        .collect()
+}